@@ -0,0 +1,24 @@
+use std::convert::TryFrom;
+
+use darling_core020::{Error as DarlingError, FromMeta};
+use syn2::{Expr, ExprLit, Meta};
+
+use super::{syn2::FromMetaError, Alias};
+
+impl FromMeta for Alias {
+    fn from_meta(meta: &Meta) -> Result<Self, DarlingError> {
+        Self::try_from(meta).map_err(|err| match err {
+            FromMetaError::MetaTypeOrPathMismatch(meta) => match meta {
+                Meta::Path(_) => DarlingError::unexpected_type("Meta::Path"),
+                Meta::List(_) => DarlingError::unexpected_type("Meta::List"),
+                Meta::NameValue(meta_name_value) => {
+                    DarlingError::unknown_field_path(&meta_name_value.path)
+                }
+            },
+            FromMetaError::LitTypeMismatch(expr) => match expr {
+                Expr::Lit(ExprLit { lit, .. }) => DarlingError::unexpected_lit_type(lit),
+                expr => DarlingError::unexpected_expr_type(expr),
+            },
+        })
+    }
+}
@@ -2,8 +2,14 @@
 
 #[cfg(feature = "with-darling")]
 pub mod darling;
+#[cfg(feature = "with-darling020")]
+pub mod darling020;
+#[cfg(feature = "with-quote")]
+pub mod quote;
 #[cfg(feature = "with-syn")]
 pub mod syn;
+#[cfg(feature = "with-syn2")]
+pub mod syn2;
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub struct Alias(pub String);
@@ -0,0 +1,12 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use super::Alias;
+
+/// Emits `alias = "..."`, the meta `TryFrom<&Meta>` parses.
+impl ToTokens for Alias {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.0;
+        tokens.extend(quote!(alias = #name));
+    }
+}
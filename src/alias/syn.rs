@@ -2,6 +2,11 @@ use std::{convert::TryFrom, error, fmt};
 
 use syn::{Lit, Meta};
 
+use crate::{
+    attrs::{meta_span, name_value_meta},
+    Error,
+};
+
 use super::Alias;
 
 /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L7)
@@ -24,10 +29,37 @@ impl<'a> TryFrom<&'a Meta> for Alias {
     }
 }
 
+/// Builds `alias = "..."`, the inverse of `TryFrom<&Meta>`.
+impl From<&Alias> for Meta {
+    fn from(alias: &Alias) -> Self {
+        name_value_meta(ALIAS, &alias.0)
+    }
+}
+
 pub enum FromMetaError<'a> {
     MetaTypeOrPathMismatch(&'a Meta),
     LitTypeMismatch(&'a Lit),
 }
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1585-L1606)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(r#"malformed {0} attribute, expected `{0} = "..."`"#, ALIAS),
+            ),
+            Self::LitTypeMismatch(lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    ALIAS
+                ),
+            ),
+        }
+    }
+}
 impl<'a> fmt::Debug for FromMetaError<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -0,0 +1,46 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn2::{Expr, Meta};
+
+use crate::syn2_attrs::expr_lit_str;
+
+use super::Alias;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/symbol.rs#L7)
+pub const ALIAS: &str = "alias";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L1084-L1088)
+impl<'a> TryFrom<&'a Meta> for Alias {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::NameValue(ref meta_name_value) if meta_name_value.path.is_ident(ALIAS) => {
+                match expr_lit_str(&meta_name_value.value) {
+                    Some(s) => Ok(Self(s.value())),
+                    None => Err(FromMetaError::LitTypeMismatch(&meta_name_value.value)),
+                }
+            }
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Expr),
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
@@ -0,0 +1,207 @@
+//! Shared helpers for collecting `#[serde(...)]` attributes.
+
+use std::{error, fmt};
+
+use proc_macro2::Span;
+use syn::{Attribute, Meta, NestedMeta};
+#[cfg(any(feature = "attr-alias", feature = "attr-rename"))]
+use syn::{Ident, Lit, LitStr, MetaNameValue};
+
+use crate::Error;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L25)
+pub const SERDE: &str = "serde";
+
+/// The attributes that skip a field in at least one direction, `skip_serializing_if` aside.
+#[cfg(feature = "attr-flatten")]
+pub(crate) const SKIP_ATTRS: &[&str] = &["skip", "skip_serializing", "skip_deserializing"];
+
+/// Flattens every `#[serde(...)]` attribute into its nested metas, skipping other attributes.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1567-L1583)
+pub fn parse_serde_meta_list(attrs: &[Attribute]) -> Result<Vec<Meta>, FromAttrsError> {
+    let mut metas = vec![];
+    for attr in attrs {
+        if !attr.path.is_ident(SERDE) {
+            continue;
+        }
+
+        match attr.parse_meta().map_err(FromAttrsError::ParseMetaFailed)? {
+            Meta::List(meta_list) => {
+                for nested_meta in meta_list.nested {
+                    match nested_meta {
+                        NestedMeta::Meta(meta) => metas.push(meta),
+                        nested_meta => {
+                            return Err(FromAttrsError::NestedMetaTypeMismatch(nested_meta))
+                        }
+                    }
+                }
+            }
+            meta => return Err(FromAttrsError::MetaTypeMismatch(meta)),
+        }
+    }
+    Ok(metas)
+}
+
+/// Stores `value` in `slot`, failing with `DuplicateAttribute` if it was already set.
+#[cfg(any(
+    feature = "attr-enum-repr",
+    feature = "attr-default",
+    feature = "attr-flatten",
+    feature = "attr-deny-unknown-fields",
+    feature = "attr-transparent",
+    feature = "attr-crate-path",
+    feature = "attr-remote",
+    feature = "attr-expecting",
+    feature = "attr-borrow",
+    feature = "attr-getter"
+))]
+pub(crate) fn set_once<T>(
+    slot: &mut Option<T>,
+    value: T,
+    meta: Meta,
+) -> Result<(), FromAttrsError> {
+    if slot.is_some() {
+        return Err(FromAttrsError::DuplicateAttribute(meta));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Stores `value` in `slot`, letting `merge` combine it with a previous value.
+///
+/// Fails with `DuplicateAttribute` if they cannot be combined.
+#[cfg(any(
+    feature = "attr-rename",
+    feature = "attr-rename-all",
+    feature = "attr-skip",
+    feature = "attr-bound"
+))]
+pub(crate) fn set_or_merge<T>(
+    slot: &mut Option<T>,
+    value: T,
+    meta: Meta,
+    merge: fn(T, T) -> Option<T>,
+) -> Result<(), FromAttrsError> {
+    match slot.take() {
+        None => *slot = Some(value),
+        Some(prev) => match merge(prev, value) {
+            Some(merged) => *slot = Some(merged),
+            None => return Err(FromAttrsError::DuplicateAttribute(meta)),
+        },
+    }
+    Ok(())
+}
+
+/// Succeeds with `value` only if no attribute was rejected while collecting it.
+///
+/// A single error is returned as is, several as [`FromAttrsError::Multiple`].
+pub(crate) fn ok_or_combined<T>(
+    value: T,
+    mut errors: Vec<FromAttrsError>,
+) -> Result<T, FromAttrsError> {
+    match errors.len() {
+        0 => Ok(value),
+        1 => Err(errors.remove(0)),
+        _ => Err(FromAttrsError::Multiple(errors)),
+    }
+}
+
+/// Where an attribute's name is, e.g. `flatten` in `#[serde(flatten)]`.
+pub(crate) fn meta_span(meta: &Meta) -> Span {
+    meta.path()
+        .segments
+        .last()
+        .map(|segment| segment.ident.span())
+        .unwrap_or_else(Span::call_site)
+}
+
+/// Where a nested meta is, see [`meta_span`].
+pub(crate) fn nested_meta_span(nested_meta: &NestedMeta) -> Span {
+    match nested_meta {
+        NestedMeta::Meta(meta) => meta_span(meta),
+        NestedMeta::Lit(lit) => lit.span(),
+    }
+}
+
+/// An attribute's name as written, e.g. `rename` in `#[serde(rename = "a")]`.
+pub(crate) fn meta_name(meta: &Meta) -> String {
+    meta.path()
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+        .unwrap_or_default()
+}
+
+/// Builds `name = "value"`, e.g. `rename = "a"`.
+#[cfg(any(feature = "attr-alias", feature = "attr-rename"))]
+pub(crate) fn name_value_meta(name: &str, value: &str) -> Meta {
+    Meta::NameValue(MetaNameValue {
+        path: Ident::new(name, Span::call_site()).into(),
+        eq_token: Default::default(),
+        lit: Lit::Str(LitStr::new(value, Span::call_site())),
+    })
+}
+
+pub enum FromAttrsError {
+    ParseMetaFailed(syn::Error),
+    MetaTypeMismatch(Meta),
+    NestedMetaTypeMismatch(NestedMeta),
+    UnknownAttribute(Meta),
+    DuplicateAttribute(Meta),
+    /// The attribute was recognized but its value was rejected, see the `Error` for why.
+    InvalidAttribute(Meta, Error),
+    /// Several attributes were rejected, in the order they were written.
+    Multiple(Vec<FromAttrsError>),
+}
+impl fmt::Debug for FromAttrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseMetaFailed(err) => write!(f, "ParseMetaFailed({})", err),
+            Self::MetaTypeMismatch(_) => write!(f, "MetaTypeMismatch"),
+            Self::NestedMetaTypeMismatch(_) => write!(f, "NestedMetaTypeMismatch"),
+            Self::UnknownAttribute(_) => write!(f, "UnknownAttribute"),
+            Self::DuplicateAttribute(_) => write!(f, "DuplicateAttribute"),
+            Self::InvalidAttribute(_, err) => write!(f, "InvalidAttribute({})", err),
+            Self::Multiple(errs) => write!(f, "Multiple({:?})", errs),
+        }
+    }
+}
+impl fmt::Display for FromAttrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl error::Error for FromAttrsError {}
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L572-L586)
+impl From<FromAttrsError> for Error {
+    fn from(err: FromAttrsError) -> Self {
+        match err {
+            FromAttrsError::ParseMetaFailed(err) => Self::from(err),
+            FromAttrsError::MetaTypeMismatch(meta) => {
+                Self::new(meta_span(&meta), "expected #[serde(...)]")
+            }
+            FromAttrsError::NestedMetaTypeMismatch(nested_meta) => Self::new(
+                nested_meta_span(&nested_meta),
+                "unexpected literal in serde attribute",
+            ),
+            FromAttrsError::UnknownAttribute(meta) => Self::new(
+                meta_span(&meta),
+                format_args!("unknown serde attribute `{}`", meta_name(&meta)),
+            ),
+            FromAttrsError::DuplicateAttribute(meta) => Self::new(
+                meta_span(&meta),
+                format_args!("duplicate serde attribute `{}`", meta_name(&meta)),
+            ),
+            FromAttrsError::InvalidAttribute(_, err) => err,
+            FromAttrsError::Multiple(errs) => errs
+                .into_iter()
+                .map(Self::from)
+                .reduce(|mut combined, err| {
+                    combined.combine(err);
+                    combined
+                })
+                .expect("at least one error"),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use std::convert::TryFrom;
+
+use darling_core::{Error as DarlingError, FromMeta};
+use syn::Meta;
+
+use super::{syn::FromMetaError, Borrow};
+
+impl FromMeta for Borrow {
+    fn from_meta(meta: &Meta) -> Result<Self, DarlingError> {
+        Self::try_from(meta).map_err(|err| match err {
+            FromMetaError::MetaTypeOrPathMismatch(meta) => match meta {
+                Meta::Path(path) => DarlingError::unknown_field_path(path),
+                Meta::List(_) => DarlingError::unexpected_type("Meta::List"),
+                Meta::NameValue(meta_name_value) => {
+                    DarlingError::unknown_field_path(&meta_name_value.path)
+                }
+            },
+            FromMetaError::LitTypeMismatch(lit) => DarlingError::unexpected_lit_type(lit),
+            FromMetaError::LifetimesParseFailed(s, err) => DarlingError::custom(err).with_span(s),
+        })
+    }
+}
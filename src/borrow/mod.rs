@@ -0,0 +1,27 @@
+//! [Official doc](https://serde.rs/field-attrs.html#borrow)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use std::collections::BTreeSet;
+
+use ::syn::Lifetime;
+
+/// `#[serde(borrow)]` or `#[serde(borrow = "'a + 'b")]`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Borrow {
+    /// Borrow every lifetime of the field type.
+    All,
+    /// Borrow only the listed lifetimes.
+    Lifetimes(BTreeSet<Lifetime>),
+}
+impl Borrow {
+    pub fn lifetimes(&self) -> Option<&BTreeSet<Lifetime>> {
+        match self {
+            Self::All => None,
+            Self::Lifetimes(lifetimes) => Some(lifetimes),
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use std::{collections::BTreeSet, convert::TryFrom, error, fmt};
+
+use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
+use syn::{
+    punctuated::Punctuated, Attribute, Data, DeriveInput, Field, Fields, GenericArgument, Lifetime,
+    Lit, LitStr, Meta, PathArguments, Token, Type,
+};
+
+use crate::{
+    attrs::{meta_span, parse_serde_meta_list, FromAttrsError},
+    Error,
+};
+
+use super::Borrow;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L8)
+pub const BORROW: &str = "borrow";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1293-L1318)
+impl<'a> TryFrom<&'a Meta> for Borrow {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::Path(ref path) if path.is_ident(BORROW) => Ok(Self::All),
+            Meta::NameValue(ref meta_name_value) if meta_name_value.path.is_ident(BORROW) => {
+                match &meta_name_value.lit {
+                    Lit::Str(ref s) => parse_lifetimes(s)
+                        .map(Self::Lifetimes)
+                        .map_err(|err| FromMetaError::LifetimesParseFailed(s, err)),
+                    lit => Err(FromMetaError::LitTypeMismatch(lit)),
+                }
+            }
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1658-L1692)
+fn parse_lifetimes(s: &LitStr) -> syn::Result<BTreeSet<Lifetime>> {
+    let lifetimes = s.parse_with(Punctuated::<Lifetime, Token![+]>::parse_separated_nonempty)?;
+    let mut set = BTreeSet::new();
+    for lifetime in lifetimes {
+        if set.contains(&lifetime) {
+            return Err(syn::Error::new(
+                lifetime.span(),
+                format!("duplicate borrowed lifetime `{}`", lifetime),
+            ));
+        }
+        set.insert(lifetime);
+    }
+    Ok(set)
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Lit),
+    LifetimesParseFailed(&'a LitStr, syn::Error),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1658-L1692)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(
+                    r#"malformed {0} attribute, expected `{0}` or `{0} = "..."`"#,
+                    BORROW
+                ),
+            ),
+            Self::LitTypeMismatch(lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    BORROW
+                ),
+            ),
+            Self::LifetimesParseFailed(_, err) => Error::from(err),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+            Self::LifetimesParseFailed(_, err) => write!(f, "LifetimesParseFailed({})", err),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
+
+/// Every lifetime the type could borrow from a `Deserializer`.
+///
+/// `S<'a, 'b>` could borrow `'a` and `'b`, while `for<'a> fn(&'a str)` could not borrow `'a`.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1855-L1920)
+pub fn borrowable_lifetimes(ty: &Type) -> BTreeSet<Lifetime> {
+    let mut lifetimes = BTreeSet::new();
+    collect_lifetimes(ty, &mut lifetimes);
+    lifetimes
+}
+
+fn collect_lifetimes(ty: &Type, out: &mut BTreeSet<Lifetime>) {
+    match ty {
+        Type::Slice(ty) => collect_lifetimes(&ty.elem, out),
+        Type::Array(ty) => collect_lifetimes(&ty.elem, out),
+        Type::Ptr(ty) => collect_lifetimes(&ty.elem, out),
+        Type::Reference(ty) => {
+            out.extend(ty.lifetime.iter().cloned());
+            collect_lifetimes(&ty.elem, out);
+        }
+        Type::Tuple(ty) => {
+            for elem in &ty.elems {
+                collect_lifetimes(elem, out);
+            }
+        }
+        Type::Path(ty) => {
+            if let Some(qself) = &ty.qself {
+                collect_lifetimes(&qself.ty, out);
+            }
+            for segment in &ty.path.segments {
+                if let PathArguments::AngleBracketed(bracketed) = &segment.arguments {
+                    for arg in &bracketed.args {
+                        match arg {
+                            GenericArgument::Lifetime(lifetime) => {
+                                out.insert(lifetime.clone());
+                            }
+                            GenericArgument::Type(ty) => collect_lifetimes(ty, out),
+                            GenericArgument::Binding(binding) => {
+                                collect_lifetimes(&binding.ty, out)
+                            }
+                            GenericArgument::Constraint(_) | GenericArgument::Const(_) => {}
+                        }
+                    }
+                }
+            }
+        }
+        Type::Paren(ty) => collect_lifetimes(&ty.elem, out),
+        Type::Group(ty) => collect_lifetimes(&ty.elem, out),
+        Type::Macro(ty) => collect_lifetimes_from_tokens(ty.mac.tokens.clone(), out),
+        _ => {}
+    }
+}
+
+fn collect_lifetimes_from_tokens(tokens: TokenStream, out: &mut BTreeSet<Lifetime>) {
+    let mut iter = tokens.into_iter();
+    while let Some(tt) = iter.next() {
+        match &tt {
+            TokenTree::Punct(op) if op.as_char() == '\'' && op.spacing() == Spacing::Joint => {
+                if let Some(TokenTree::Ident(ident)) = iter.next() {
+                    out.insert(Lifetime {
+                        apostrophe: op.span(),
+                        ident,
+                    });
+                }
+            }
+            TokenTree::Group(group) => collect_lifetimes_from_tokens(group.stream(), out),
+            _ => {}
+        }
+    }
+}
+
+/// Flags the `borrow` attributes serde rejects, stopping at the first one.
+///
+/// A `borrow` on a variant applies to its only field, so the variant must be a newtype.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1837-L1853)
+pub fn check_borrow(input: &DeriveInput) -> Result<(), CheckBorrowError> {
+    let declared: BTreeSet<&Lifetime> = input
+        .generics
+        .lifetimes()
+        .map(|lifetime_def| &lifetime_def.lifetime)
+        .collect();
+
+    let mut borrowing_fields: Vec<(Meta, &Field)> = vec![];
+    match &input.data {
+        Data::Struct(data_struct) => {
+            for field in &data_struct.fields {
+                if let Some(meta) = find_borrow(&field.attrs)? {
+                    borrowing_fields.push((meta, field));
+                }
+            }
+        }
+        Data::Enum(data_enum) => {
+            for variant in &data_enum.variants {
+                if let Some(meta) = find_borrow(&variant.attrs)? {
+                    match &variant.fields {
+                        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                            borrowing_fields.push((meta, &fields.unnamed[0]));
+                        }
+                        _ => return Err(CheckBorrowError::NotNewtypeVariant(meta_span(&meta))),
+                    }
+                }
+                for field in &variant.fields {
+                    if let Some(meta) = find_borrow(&field.attrs)? {
+                        borrowing_fields.push((meta, field));
+                    }
+                }
+            }
+        }
+        Data::Union(_) => {}
+    }
+
+    for (meta, field) in borrowing_fields {
+        let borrow = match Borrow::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+            Ok(borrow) => borrow,
+            Err(msg) => return Err(FromAttrsError::InvalidAttribute(meta, msg).into()),
+        };
+
+        let borrowable = borrowable_lifetimes(&field.ty);
+        if borrowable.is_empty() {
+            return Err(CheckBorrowError::NoLifetimes(meta_span(&meta)));
+        }
+        for lifetime in borrow.lifetimes().into_iter().flatten() {
+            if !declared.contains(lifetime) {
+                return Err(CheckBorrowError::UndeclaredLifetime(lifetime.to_owned()));
+            }
+            if !borrowable.contains(lifetime) {
+                return Err(CheckBorrowError::LifetimeNotInField(lifetime.to_owned()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn find_borrow(attrs: &[Attribute]) -> Result<Option<Meta>, FromAttrsError> {
+    Ok(parse_serde_meta_list(attrs)?
+        .into_iter()
+        .find(|meta| meta.path().is_ident(BORROW)))
+}
+
+/// The `Span` points at the `borrow` attribute, a `Lifetime` carries its own.
+pub enum CheckBorrowError {
+    FromAttrsFailed(FromAttrsError),
+    /// `borrow` on a variant that is not a newtype variant.
+    NotNewtypeVariant(Span),
+    /// `borrow` on a field whose type has no lifetimes.
+    NoLifetimes(Span),
+    /// A listed lifetime the container does not declare.
+    UndeclaredLifetime(Lifetime),
+    /// A listed lifetime the field type does not use.
+    LifetimeNotInField(Lifetime),
+}
+impl From<FromAttrsError> for CheckBorrowError {
+    fn from(err: FromAttrsError) -> Self {
+        Self::FromAttrsFailed(err)
+    }
+}
+impl fmt::Debug for CheckBorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "FromAttrsFailed({:?})", err),
+            Self::NotNewtypeVariant(_) => write!(f, "NotNewtypeVariant"),
+            Self::NoLifetimes(_) => write!(f, "NoLifetimes"),
+            Self::UndeclaredLifetime(lifetime) => write!(f, "UndeclaredLifetime({})", lifetime),
+            Self::LifetimeNotInField(lifetime) => write!(f, "LifetimeNotInField({})", lifetime),
+        }
+    }
+}
+impl fmt::Display for CheckBorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "{}", err),
+            Self::NotNewtypeVariant(_) => {
+                write!(f, "#[serde(borrow)] may only be used on newtype variants")
+            }
+            Self::NoLifetimes(_) => write!(f, "field has no lifetimes to borrow"),
+            Self::UndeclaredLifetime(lifetime) => {
+                write!(f, "use of undeclared lifetime name `{}`", lifetime)
+            }
+            Self::LifetimeNotInField(lifetime) => {
+                write!(f, "field does not have lifetime {}", lifetime)
+            }
+        }
+    }
+}
+impl error::Error for CheckBorrowError {}
+impl From<CheckBorrowError> for Error {
+    fn from(err: CheckBorrowError) -> Self {
+        match err {
+            CheckBorrowError::FromAttrsFailed(err) => Self::from(err),
+            CheckBorrowError::NotNewtypeVariant(span) | CheckBorrowError::NoLifetimes(span) => {
+                Self::new(span, err)
+            }
+            CheckBorrowError::UndeclaredLifetime(ref lifetime)
+            | CheckBorrowError::LifetimeNotInField(ref lifetime) => {
+                Self::new(lifetime.span(), &err)
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! [Official doc](https://serde.rs/container-attrs.html#bound)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use ::syn::{Generics, WherePredicate};
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Bound {
+    Normal(Vec<WherePredicate>),
+    Independent(BoundIndependent),
+}
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum BoundIndependent {
+    Serialize(Vec<WherePredicate>),
+    Deserialize(Vec<WherePredicate>),
+    Both {
+        serialize: Vec<WherePredicate>,
+        deserialize: Vec<WherePredicate>,
+    },
+}
+impl Bound {
+    pub fn ser_bound(&self) -> Option<&[WherePredicate]> {
+        match self {
+            Self::Normal(predicates)
+            | Self::Independent(BoundIndependent::Serialize(predicates))
+            | Self::Independent(BoundIndependent::Both {
+                serialize: predicates,
+                deserialize: _,
+            }) => Some(predicates),
+            _ => None,
+        }
+    }
+
+    pub fn de_bound(&self) -> Option<&[WherePredicate]> {
+        match self {
+            Self::Normal(predicates)
+            | Self::Independent(BoundIndependent::Deserialize(predicates))
+            | Self::Independent(BoundIndependent::Both {
+                serialize: _,
+                deserialize: predicates,
+            }) => Some(predicates),
+            _ => None,
+        }
+    }
+
+    /// Combines `bound(serialize = "...")` and `bound(deserialize = "...")` from separate
+    /// attributes, `None` if both set the same side.
+    pub fn merge(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (
+                Self::Independent(BoundIndependent::Serialize(serialize)),
+                Self::Independent(BoundIndependent::Deserialize(deserialize)),
+            )
+            | (
+                Self::Independent(BoundIndependent::Deserialize(deserialize)),
+                Self::Independent(BoundIndependent::Serialize(serialize)),
+            ) => Some(Self::Independent(BoundIndependent::Both {
+                serialize,
+                deserialize,
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// Appends `predicates` to the where-clause of a copy of `generics`, e.g. with
+/// [`Bound::ser_bound`] for the generics of a generated `Serialize` impl.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/bound.rs#L32-L42)
+pub fn with_where_predicates(generics: &Generics, predicates: &[WherePredicate]) -> Generics {
+    let mut generics = generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(predicates.iter().cloned());
+    generics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ::syn::parse_quote;
+
+    #[test]
+    fn test_ser_bound_and_de_bound() {
+        let predicates: Vec<WherePredicate> = vec![parse_quote!(T: Clone)];
+
+        let bound = Bound::Normal(predicates.clone());
+        assert_eq!(bound.ser_bound(), Some(&predicates[..]));
+        assert_eq!(bound.de_bound(), Some(&predicates[..]));
+
+        let bound = Bound::Independent(BoundIndependent::Serialize(predicates.clone()));
+        assert_eq!(bound.ser_bound(), Some(&predicates[..]));
+        assert_eq!(bound.de_bound(), None);
+
+        let bound = Bound::Independent(BoundIndependent::Deserialize(predicates.clone()));
+        assert_eq!(bound.ser_bound(), None);
+        assert_eq!(bound.de_bound(), Some(&predicates[..]));
+    }
+
+    #[test]
+    fn test_merge() {
+        let ser: Vec<WherePredicate> = vec![parse_quote!(T: Clone)];
+        let de: Vec<WherePredicate> = vec![parse_quote!(T: Default)];
+
+        assert_eq!(
+            Bound::Independent(BoundIndependent::Deserialize(de.clone()))
+                .merge(Bound::Independent(BoundIndependent::Serialize(ser.clone()))),
+            Some(Bound::Independent(BoundIndependent::Both {
+                serialize: ser.clone(),
+                deserialize: de.clone(),
+            }))
+        );
+        assert_eq!(
+            Bound::Normal(ser.clone()).merge(Bound::Independent(BoundIndependent::Deserialize(de))),
+            None
+        );
+        assert_eq!(
+            Bound::Independent(BoundIndependent::Serialize(ser.clone()))
+                .merge(Bound::Independent(BoundIndependent::Serialize(ser))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_with_where_predicates() {
+        let generics: Generics = parse_quote!(<T>);
+        let generics = with_where_predicates(&generics, &[parse_quote!(T: Clone)]);
+        let expected: WherePredicate = parse_quote!(T: Clone);
+        assert_eq!(
+            generics
+                .where_clause
+                .map(|where_clause| where_clause.predicates.into_iter().collect::<Vec<_>>()),
+            Some(vec![expected])
+        );
+    }
+}
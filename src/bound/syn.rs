@@ -0,0 +1,147 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn::{Lit, LitStr, Meta, MetaNameValue, NestedMeta, WhereClause, WherePredicate};
+
+use crate::{
+    attrs::{meta_span, nested_meta_span},
+    Error, DESERIALIZE, SERIALIZE,
+};
+
+use super::{Bound, BoundIndependent};
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L9)
+pub const BOUND: &str = "bound";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L428-L443)
+impl<'a> TryFrom<&'a Meta> for Bound {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::NameValue(ref meta_name_value) if meta_name_value.path.is_ident(BOUND) => {
+                parse_lit_into_where(&meta_name_value.lit).map(Self::Normal)
+            }
+            Meta::List(ref meta_list) if meta_list.path.is_ident(BOUND) => {
+                let mut ser_bound = None;
+                let mut de_bound = None;
+
+                for nested_meta in &meta_list.nested {
+                    match nested_meta {
+                        NestedMeta::Meta(Meta::NameValue(meta_name_value)) => {
+                            if meta_name_value.path.is_ident(SERIALIZE) {
+                                ser_bound = Some(parse_lit_into_where(&meta_name_value.lit)?);
+                            } else if meta_name_value.path.is_ident(DESERIALIZE) {
+                                de_bound = Some(parse_lit_into_where(&meta_name_value.lit)?);
+                            } else {
+                                return Err(FromMetaError::NestedMetaPathMismatch(
+                                    nested_meta,
+                                    meta_name_value,
+                                ));
+                            }
+                        }
+                        nested_meta => {
+                            return Err(FromMetaError::NestedMetaTypeMismatch(nested_meta))
+                        }
+                    }
+                }
+                match (ser_bound, de_bound) {
+                    (None, None) => Err(FromMetaError::AtLeastOneOfSerAndDe),
+                    (None, Some(de_bound)) => {
+                        Ok(Self::Independent(BoundIndependent::Deserialize(de_bound)))
+                    }
+                    (Some(ser_bound), None) => {
+                        Ok(Self::Independent(BoundIndependent::Serialize(ser_bound)))
+                    }
+                    (Some(ser_bound), Some(de_bound)) => {
+                        Ok(Self::Independent(BoundIndependent::Both {
+                            serialize: ser_bound,
+                            deserialize: de_bound,
+                        }))
+                    }
+                }
+            }
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1627-L1643)
+fn parse_lit_into_where(lit: &Lit) -> Result<Vec<WherePredicate>, FromMetaError<'_>> {
+    let s = match lit {
+        Lit::Str(s) => s,
+        lit => return Err(FromMetaError::LitTypeMismatch(lit)),
+    };
+    if s.value().is_empty() {
+        return Ok(vec![]);
+    }
+    LitStr::new(&format!("where {}", s.value()), s.span())
+        .parse::<WhereClause>()
+        .map(|where_clause| where_clause.predicates.into_iter().collect())
+        .map_err(|err| FromMetaError::WherePredicatesParseFailed(s, err))
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Lit),
+    WherePredicatesParseFailed(&'a LitStr, syn::Error),
+    NestedMetaTypeMismatch(&'a NestedMeta),
+    NestedMetaPathMismatch(&'a NestedMeta, &'a MetaNameValue),
+    AtLeastOneOfSerAndDe,
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1627-L1643)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(r#"malformed {0} attribute, expected `{0} = "..."` or `{0}(serialize = "...", deserialize = "...")`"#, BOUND),
+            ),
+            Self::LitTypeMismatch(lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    BOUND
+                ),
+            ),
+            Self::WherePredicatesParseFailed(s, err) => Error::new(s.span(), err),
+            Self::NestedMetaTypeMismatch(nested_meta) | Self::NestedMetaPathMismatch(nested_meta, _) => {
+                Error::new(
+                    nested_meta_span(nested_meta),
+                    format_args!(
+                        "malformed {0} attribute, expected `{0}(serialize = ..., deserialize = ...)`",
+                        BOUND
+                    ),
+                )
+            }
+            Self::AtLeastOneOfSerAndDe => Error::new(
+                meta_span(meta),
+                format_args!(
+                    "malformed {0} attribute, expected at least one of `serialize` and `deserialize`",
+                    BOUND
+                ),
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+            Self::WherePredicatesParseFailed(_, err) => {
+                write!(f, "WherePredicatesParseFailed({})", err)
+            }
+            Self::NestedMetaTypeMismatch(_) => write!(f, "NestedMetaTypeMismatch"),
+            Self::NestedMetaPathMismatch(_, _) => write!(f, "NestedMetaPathMismatch"),
+            Self::AtLeastOneOfSerAndDe => write!(f, "AtLeastOneOfSerAndDe"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
@@ -0,0 +1,88 @@
+//! [Official doc](https://serde.rs/container-attrs.html)
+
+pub mod syn;
+
+#[cfg(feature = "attr-crate-path")]
+use std::borrow::Cow;
+
+#[cfg(feature = "attr-crate-path")]
+use ::syn::Path;
+
+#[cfg(feature = "attr-default")]
+use crate::default::Default;
+#[cfg(feature = "attr-bound")]
+use crate::Bound;
+#[cfg(feature = "attr-conversion")]
+use crate::Conversion;
+#[cfg(feature = "attr-crate-path")]
+use crate::CratePath;
+#[cfg(feature = "attr-deny-unknown-fields")]
+use crate::DenyUnknownFields;
+#[cfg(feature = "attr-enum-repr")]
+use crate::EnumRepr;
+#[cfg(feature = "attr-expecting")]
+use crate::Expecting;
+#[cfg(feature = "attr-identifier")]
+use crate::Identifier;
+#[cfg(feature = "attr-remote")]
+use crate::Remote;
+#[cfg(feature = "attr-rename")]
+use crate::Rename;
+#[cfg(feature = "attr-rename-all")]
+use crate::RenameAll;
+#[cfg(feature = "attr-rename-all-fields")]
+use crate::RenameAllFields;
+#[cfg(feature = "attr-transparent")]
+use crate::Transparent;
+
+/// All `#[serde(...)]` attributes of a struct or enum.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Container {
+    #[cfg(feature = "attr-rename")]
+    pub rename: Option<Rename>,
+    #[cfg(feature = "attr-rename-all")]
+    pub rename_all: Option<RenameAll>,
+    #[cfg(feature = "attr-rename-all-fields")]
+    pub rename_all_fields: Option<RenameAllFields>,
+    #[cfg(feature = "attr-enum-repr")]
+    pub enum_repr: EnumRepr,
+    #[cfg(feature = "attr-default")]
+    pub default: Option<Default>,
+    #[cfg(feature = "attr-deny-unknown-fields")]
+    pub deny_unknown_fields: Option<DenyUnknownFields>,
+    #[cfg(feature = "attr-transparent")]
+    pub transparent: Option<Transparent>,
+    #[cfg(feature = "attr-conversion")]
+    pub conversion: Option<Conversion>,
+    #[cfg(feature = "attr-bound")]
+    pub bound: Option<Bound>,
+    #[cfg(feature = "attr-crate-path")]
+    pub crate_path: Option<CratePath>,
+    #[cfg(feature = "attr-remote")]
+    pub remote: Option<Remote>,
+    #[cfg(feature = "attr-identifier")]
+    pub identifier: Identifier,
+    #[cfg(feature = "attr-expecting")]
+    pub expecting: Option<Expecting>,
+}
+impl Container {
+    /// The path generated code should reach serde at, `::serde` unless overridden.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L694-L697)
+    #[cfg(feature = "attr-crate-path")]
+    pub fn serde_path(&self) -> Cow<'_, Path> {
+        match &self.crate_path {
+            Some(crate_path) => Cow::Borrowed(crate_path.path()),
+            None => Cow::Owned(CratePath::default().0),
+        }
+    }
+
+    /// Whether serde_derive emits inherent `serialize`/`deserialize` functions on the item
+    /// instead of implementing `Serialize`/`Deserialize` for it.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/ser.rs#L31-L57)
+    #[cfg(feature = "attr-remote")]
+    pub fn emits_inherent_fns(&self) -> bool {
+        self.remote.is_some()
+    }
+}
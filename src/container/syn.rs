@@ -0,0 +1,400 @@
+use std::convert::TryFrom;
+
+#[cfg(any(feature = "attr-enum-repr", feature = "attr-rename-all-fields"))]
+use syn::Data;
+use syn::{Attribute, DeriveInput};
+#[cfg(feature = "attr-enum-repr")]
+use syn::{DataStruct, Fields};
+
+#[cfg(any(
+    feature = "attr-default",
+    feature = "attr-crate-path",
+    feature = "attr-remote",
+    feature = "attr-expecting",
+    feature = "attr-deny-unknown-fields",
+    feature = "attr-transparent"
+))]
+use crate::attrs::set_once;
+#[cfg(any(
+    feature = "attr-rename",
+    feature = "attr-rename-all",
+    feature = "attr-bound"
+))]
+use crate::attrs::set_or_merge;
+use crate::attrs::{ok_or_combined, parse_serde_meta_list, FromAttrsError};
+#[cfg(feature = "attr-bound")]
+use crate::bound::{syn::BOUND, Bound};
+#[cfg(feature = "attr-conversion")]
+use crate::conversion::{
+    self,
+    syn::{FROM, INTO, TRY_FROM},
+};
+#[cfg(feature = "attr-default")]
+use crate::default::{syn::DEFAULT, Default};
+#[cfg(any(feature = "attr-enum-repr", feature = "attr-rename-all-fields"))]
+use crate::{attrs::meta_span, Error};
+#[cfg(feature = "attr-crate-path")]
+use crate::{crate_path::syn::CRATE, CratePath};
+#[cfg(feature = "attr-deny-unknown-fields")]
+use crate::{deny_unknown_fields::syn::DENY_UNKNOWN_FIELDS, DenyUnknownFields};
+#[cfg(feature = "attr-enum-repr")]
+use crate::{
+    enum_repr::syn::{FromMetaError as EnumReprFromMetaError, CONTENT, TAG, UNTAGGED},
+    EnumRepr,
+};
+#[cfg(feature = "attr-expecting")]
+use crate::{expecting::syn::EXPECTING, Expecting};
+#[cfg(feature = "attr-identifier")]
+use crate::{
+    identifier::syn::{
+        FromMetaError as IdentifierFromMetaError, FIELD_IDENTIFIER, VARIANT_IDENTIFIER,
+    },
+    Identifier,
+};
+#[cfg(feature = "attr-remote")]
+use crate::{remote::syn::REMOTE, Remote};
+#[cfg(feature = "attr-rename")]
+use crate::{rename::syn::RENAME, Rename};
+#[cfg(feature = "attr-rename-all")]
+use crate::{rename_all::syn::RENAME_ALL, RenameAll};
+#[cfg(feature = "attr-rename-all-fields")]
+use crate::{rename_all_fields::syn::RENAME_ALL_FIELDS, RenameAllFields};
+#[cfg(feature = "attr-transparent")]
+use crate::{transparent::syn::TRANSPARENT, Transparent};
+
+use super::Container;
+
+/// Every container attribute serde knows about.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L290-L311)
+pub const CONTAINER_ATTRS: &[&str] = &[
+    "rename",
+    "rename_all",
+    "rename_all_fields",
+    "transparent",
+    "deny_unknown_fields",
+    "default",
+    "bound",
+    "untagged",
+    "tag",
+    "content",
+    "from",
+    "try_from",
+    "into",
+    "remote",
+    "field_identifier",
+    "variant_identifier",
+    "crate",
+    "expecting",
+];
+
+impl Container {
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L290-L588)
+    pub fn try_from_attrs(attrs: &[Attribute]) -> Result<Self, FromAttrsError> {
+        #[allow(unused_mut)]
+        let mut container = Self::default();
+        let mut errors = vec![];
+        #[cfg(feature = "attr-enum-repr")]
+        let mut enum_repr_metas = vec![];
+        #[cfg(feature = "attr-conversion")]
+        let mut conversion_metas = vec![];
+        #[cfg(feature = "attr-identifier")]
+        let mut identifier_metas = vec![];
+
+        for meta in parse_serde_meta_list(attrs)? {
+            #[cfg(feature = "attr-rename")]
+            if meta.path().is_ident(RENAME) {
+                let result = match Rename::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(rename) => set_or_merge(&mut container.rename, rename, meta, Rename::merge),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-rename-all")]
+            if meta.path().is_ident(RENAME_ALL) {
+                let result = match RenameAll::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(rename_all) => set_or_merge(
+                        &mut container.rename_all,
+                        rename_all,
+                        meta,
+                        RenameAll::merge,
+                    ),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-rename-all-fields")]
+            if meta.path().is_ident(RENAME_ALL_FIELDS) {
+                let result =
+                    match RenameAllFields::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                        Ok(rename_all_fields) => set_or_merge(
+                            &mut container.rename_all_fields,
+                            rename_all_fields,
+                            meta,
+                            RenameAllFields::merge,
+                        ),
+                        Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                    };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-bound")]
+            if meta.path().is_ident(BOUND) {
+                let result = match Bound::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(bound) => set_or_merge(&mut container.bound, bound, meta, Bound::merge),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-enum-repr")]
+            if [TAG, CONTENT, UNTAGGED]
+                .iter()
+                .any(|name| meta.path().is_ident(name))
+            {
+                enum_repr_metas.push(meta);
+                continue;
+            }
+
+            #[cfg(feature = "attr-identifier")]
+            if [FIELD_IDENTIFIER, VARIANT_IDENTIFIER]
+                .iter()
+                .any(|name| meta.path().is_ident(name))
+            {
+                identifier_metas.push(meta);
+                continue;
+            }
+
+            #[cfg(feature = "attr-deny-unknown-fields")]
+            if meta.path().is_ident(DENY_UNKNOWN_FIELDS) {
+                let result =
+                    match DenyUnknownFields::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                        Ok(deny_unknown_fields) => set_once(
+                            &mut container.deny_unknown_fields,
+                            deny_unknown_fields,
+                            meta,
+                        ),
+                        Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                    };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-transparent")]
+            if meta.path().is_ident(TRANSPARENT) {
+                let result = match Transparent::try_from(&meta).map_err(|err| err.into_error(&meta))
+                {
+                    Ok(transparent) => set_once(&mut container.transparent, transparent, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-crate-path")]
+            if meta.path().is_ident(CRATE) {
+                let result = match CratePath::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(crate_path) => set_once(&mut container.crate_path, crate_path, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-remote")]
+            if meta.path().is_ident(REMOTE) {
+                let result = match Remote::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(remote) => set_once(&mut container.remote, remote, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-expecting")]
+            if meta.path().is_ident(EXPECTING) {
+                let result = match Expecting::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(expecting) => set_once(&mut container.expecting, expecting, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-conversion")]
+            if [FROM, TRY_FROM, INTO]
+                .iter()
+                .any(|name| meta.path().is_ident(name))
+            {
+                conversion_metas.push(meta);
+                continue;
+            }
+
+            #[cfg(feature = "attr-default")]
+            if meta.path().is_ident(DEFAULT) {
+                let result = match Default::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(default) => set_once(&mut container.default, default, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            if !CONTAINER_ATTRS
+                .iter()
+                .any(|name| meta.path().is_ident(name))
+            {
+                errors.push(FromAttrsError::UnknownAttribute(meta));
+            }
+        }
+
+        #[cfg(feature = "attr-enum-repr")]
+        {
+            let result = EnumRepr::try_from(&enum_repr_metas[..]).map_err(|err| match err {
+                EnumReprFromMetaError::DuplicateMeta(meta) => {
+                    FromAttrsError::DuplicateAttribute(meta.to_owned())
+                }
+                EnumReprFromMetaError::MetaTypeOrPathMismatch(meta)
+                | EnumReprFromMetaError::LitTypeMismatch(meta, _)
+                | EnumReprFromMetaError::ContentWithoutTag(meta)
+                | EnumReprFromMetaError::UntaggedWithTag(meta, _)
+                | EnumReprFromMetaError::UntaggedWithContent(meta, _)
+                | EnumReprFromMetaError::UntaggedWithTagAndContent(meta, _, _) => {
+                    FromAttrsError::InvalidAttribute(meta.to_owned(), err.into_error())
+                }
+            });
+            match result {
+                Ok(enum_repr) => container.enum_repr = enum_repr,
+                Err(err) => errors.push(err),
+            }
+        }
+
+        #[cfg(feature = "attr-identifier")]
+        {
+            let result = Identifier::try_from(&identifier_metas[..]).map_err(|err| match err {
+                IdentifierFromMetaError::DuplicateMeta(meta) => {
+                    FromAttrsError::DuplicateAttribute(meta.to_owned())
+                }
+                IdentifierFromMetaError::MetaTypeOrPathMismatch(meta)
+                | IdentifierFromMetaError::FieldWithVariant(_, meta) => {
+                    FromAttrsError::InvalidAttribute(meta.to_owned(), err.into_error())
+                }
+            });
+            match result {
+                Ok(identifier) => container.identifier = identifier,
+                Err(err) => errors.push(err),
+            }
+        }
+
+        #[cfg(feature = "attr-conversion")]
+        {
+            match conversion::syn::try_from_attr_metas(&conversion_metas) {
+                Ok(conversion) => container.conversion = conversion,
+                Err(err) => errors.push(err),
+            }
+        }
+
+        ok_or_combined(container, errors)
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for Container {
+    type Error = FromAttrsError;
+
+    fn try_from(derive_input: &'a DeriveInput) -> Result<Self, Self::Error> {
+        #[allow(unused_mut)]
+        let mut container = Self::try_from_attrs(&derive_input.attrs)?;
+
+        #[cfg(feature = "attr-remote")]
+        if let Some(remote) = &mut container.remote {
+            if remote.0.is_ident("Self") {
+                remote.0 = derive_input.ident.clone().into();
+            }
+        }
+
+        #[cfg(any(feature = "attr-enum-repr", feature = "attr-rename-all-fields"))]
+        check_enum_only_attrs(derive_input)?;
+
+        #[cfg(feature = "attr-enum-repr")]
+        check_enum_repr_variants(derive_input, &container.enum_repr)?;
+
+        Ok(container)
+    }
+}
+
+/// `untagged`, `content` and `rename_all_fields` only apply to enums, `tag` to enums and structs
+/// with named fields.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L303-L452)
+#[cfg(any(feature = "attr-enum-repr", feature = "attr-rename-all-fields"))]
+#[allow(clippy::vec_init_then_push)]
+fn check_enum_only_attrs(derive_input: &DeriveInput) -> Result<(), FromAttrsError> {
+    if let Data::Enum(_) = derive_input.data {
+        return Ok(());
+    }
+
+    let mut enum_only: Vec<(&str, &str)> = vec![];
+    #[cfg(feature = "attr-enum-repr")]
+    {
+        enum_only.push((UNTAGGED, "#[serde(untagged)] can only be used on enums"));
+        enum_only.push((
+            CONTENT,
+            r#"#[serde(content = "...")] can only be used on enums"#,
+        ));
+        if !matches!(
+            derive_input.data,
+            Data::Struct(DataStruct {
+                fields: Fields::Named(_),
+                ..
+            })
+        ) {
+            enum_only.push((
+                TAG,
+                r#"#[serde(tag = "...")] can only be used on enums and structs with named fields"#,
+            ));
+        }
+    }
+    #[cfg(feature = "attr-rename-all-fields")]
+    enum_only.push((
+        RENAME_ALL_FIELDS,
+        "#[serde(rename_all_fields)] can only be used on enums",
+    ));
+
+    let mut errors = vec![];
+    for meta in parse_serde_meta_list(&derive_input.attrs)? {
+        if let Some((_, msg)) = enum_only
+            .iter()
+            .find(|(name, _)| meta.path().is_ident(name))
+        {
+            let err = Error::new(meta_span(&meta), msg);
+            errors.push(FromAttrsError::InvalidAttribute(meta, err));
+        }
+    }
+    ok_or_combined((), errors)
+}
+
+/// Blames the `tag` attribute for the tuple variants an internally tagged enum can't have, see
+/// [`EnumRepr::check_variants`].
+#[cfg(feature = "attr-enum-repr")]
+fn check_enum_repr_variants(
+    derive_input: &DeriveInput,
+    enum_repr: &EnumRepr,
+) -> Result<(), FromAttrsError> {
+    if let Data::Enum(data_enum) = &derive_input.data {
+        if let Err(err) = enum_repr.check_variants(data_enum) {
+            if let Some(meta) = parse_serde_meta_list(&derive_input.attrs)?
+                .into_iter()
+                .find(|meta| meta.path().is_ident(TAG))
+            {
+                return Err(FromAttrsError::InvalidAttribute(meta, err));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use std::convert::TryFrom;
+
+use darling_core::{Error as DarlingError, FromMeta};
+use syn::{Meta, NestedMeta};
+
+use crate::attrs::SERDE;
+
+use super::{syn::FromMetaError, Conversion};
+
+fn map_err(err: FromMetaError<'_>) -> DarlingError {
+    match err {
+        FromMetaError::MetaTypeOrPathMismatch(meta) => match meta {
+            Meta::Path(path) => DarlingError::unknown_field_path(path),
+            Meta::List(meta_list) => DarlingError::unknown_field_path(&meta_list.path),
+            Meta::NameValue(meta_name_value) => {
+                DarlingError::unknown_field_path(&meta_name_value.path)
+            }
+        },
+        FromMetaError::LitTypeMismatch(_, lit) => DarlingError::unexpected_lit_type(lit),
+        FromMetaError::TypeParseFailed(_, s, err) => DarlingError::custom(err).with_span(s),
+        FromMetaError::DuplicateMeta(meta) => DarlingError::duplicate_field_path(meta.path()),
+        FromMetaError::FromWithTryFrom(_, meta) => {
+            DarlingError::custom("from and try_from conflict with each other").with_span(meta)
+        }
+    }
+}
+
+impl FromMeta for Conversion {
+    /// A single `from`, `try_from` or `into`, or a whole `serde(...)` list.
+    fn from_meta(meta: &Meta) -> Result<Self, DarlingError> {
+        match meta {
+            Meta::List(meta_list) if meta_list.path.is_ident(SERDE) => {
+                Self::from_list(&meta_list.nested.iter().cloned().collect::<Vec<_>>()[..])
+            }
+            meta => Self::try_from(meta).map_err(map_err),
+        }
+    }
+
+    /// The items of a `#[serde(...)]` list, e.g. `#[serde(from = "A", into = "B")]`.
+    fn from_list(items: &[NestedMeta]) -> Result<Self, DarlingError> {
+        let metas = items
+            .iter()
+            .map(|nested_meta| match nested_meta {
+                NestedMeta::Meta(meta) => Ok(meta.to_owned()),
+                NestedMeta::Lit(lit) => Err(DarlingError::unexpected_lit_type(lit)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::try_from_metas(&metas[..])
+            .map_err(map_err)?
+            .ok_or_else(|| DarlingError::custom("expected from, try_from or into"))
+    }
+}
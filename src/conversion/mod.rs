@@ -0,0 +1,69 @@
+//! [Official doc](https://serde.rs/container-attrs.html#from)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use ::syn::Type;
+
+/// `from = "Type"`, `try_from = "Type"` and `into = "Type"`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Default)]
+pub struct Conversion {
+    pub from: Option<ConversionFrom>,
+    pub into: Option<Type>,
+}
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum ConversionFrom {
+    /// `from = "Type"`, deserialize `Type` then `From::from` it.
+    From(Type),
+    /// `try_from = "Type"`, deserialize `Type` then `TryFrom::try_from` it.
+    TryFrom(Type),
+}
+impl Conversion {
+    /// The type to serialize instead, after `Into::into`.
+    pub fn ser_type(&self) -> Option<&Type> {
+        self.into.as_ref()
+    }
+
+    /// The type to deserialize instead, see [`Conversion::is_fallible`] for how to convert it.
+    pub fn de_type(&self) -> Option<&Type> {
+        match &self.from {
+            Some(ConversionFrom::From(ty)) | Some(ConversionFrom::TryFrom(ty)) => Some(ty),
+            None => None,
+        }
+    }
+
+    /// Whether `de_type` is converted with `TryFrom` rather than `From`.
+    pub fn is_fallible(&self) -> bool {
+        matches!(self.from, Some(ConversionFrom::TryFrom(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ::syn::parse_quote;
+
+    #[test]
+    fn test_ser_type_and_de_type() {
+        let conversion = Conversion {
+            from: Some(ConversionFrom::From(parse_quote!(u8))),
+            into: Some(parse_quote!(u16)),
+        };
+        assert_eq!(conversion.ser_type(), Some(&parse_quote!(u16)));
+        assert_eq!(conversion.de_type(), Some(&parse_quote!(u8)));
+        assert!(!conversion.is_fallible());
+
+        let conversion = Conversion {
+            from: Some(ConversionFrom::TryFrom(parse_quote!(u8))),
+            into: None,
+        };
+        assert_eq!(conversion.ser_type(), None);
+        assert_eq!(conversion.de_type(), Some(&parse_quote!(u8)));
+        assert!(conversion.is_fallible());
+
+        assert_eq!(Conversion::default().de_type(), None);
+    }
+}
@@ -0,0 +1,162 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn::{Lit, LitStr, Meta, Type};
+
+use crate::{
+    attrs::{meta_name, meta_span, FromAttrsError},
+    Error,
+};
+
+use super::{Conversion, ConversionFrom};
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L18)
+pub const FROM: &str = "from";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L34)
+pub const TRY_FROM: &str = "try_from";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L20)
+pub const INTO: &str = "into";
+
+impl Conversion {
+    /// Takes the metas of a container's `#[serde(...)]` lists, ignoring the ones that are not
+    /// `from`, `try_from` or `into`.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L514-L533)
+    pub fn try_from_metas<'a>(metas: &'a [Meta]) -> Result<Option<Self>, FromMetaError<'a>> {
+        let mut from: Option<(&'a Meta, Type)> = None;
+        let mut try_from: Option<(&'a Meta, Type)> = None;
+        let mut into: Option<(&'a Meta, Type)> = None;
+
+        for meta in metas {
+            let slot = if meta.path().is_ident(FROM) {
+                &mut from
+            } else if meta.path().is_ident(TRY_FROM) {
+                &mut try_from
+            } else if meta.path().is_ident(INTO) {
+                &mut into
+            } else {
+                continue;
+            };
+            let ty = parse_type(meta)?;
+            if slot.is_some() {
+                return Err(FromMetaError::DuplicateMeta(meta));
+            }
+            *slot = Some((meta, ty));
+        }
+
+        let from = match (from, try_from) {
+            (Some((from_meta, _)), Some((try_from_meta, _))) => {
+                return Err(FromMetaError::FromWithTryFrom(from_meta, try_from_meta))
+            }
+            (Some((_, ty)), None) => Some(ConversionFrom::From(ty)),
+            (None, Some((_, ty))) => Some(ConversionFrom::TryFrom(ty)),
+            (None, None) => None,
+        };
+        let into = into.map(|(_, ty)| ty);
+
+        if from.is_none() && into.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(Self { from, into }))
+    }
+}
+
+/// [`Conversion::try_from_metas`] for the `Container` aggregate.
+pub(crate) fn try_from_attr_metas(metas: &[Meta]) -> Result<Option<Conversion>, FromAttrsError> {
+    Conversion::try_from_metas(metas).map_err(|err| match err {
+        FromMetaError::DuplicateMeta(meta) => FromAttrsError::DuplicateAttribute(meta.to_owned()),
+        FromMetaError::MetaTypeOrPathMismatch(meta)
+        | FromMetaError::LitTypeMismatch(meta, _)
+        | FromMetaError::TypeParseFailed(meta, _, _)
+        | FromMetaError::FromWithTryFrom(_, meta) => {
+            FromAttrsError::InvalidAttribute(meta.to_owned(), err.into_error())
+        }
+    })
+}
+
+/// Takes a single `from`, `try_from` or `into`.
+impl<'a> TryFrom<&'a Meta> for Conversion {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match Self::try_from_metas(std::slice::from_ref(meta))? {
+            Some(conversion) => Ok(conversion),
+            None => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1645-L1654)
+fn parse_type(meta: &Meta) -> Result<Type, FromMetaError<'_>> {
+    match meta {
+        Meta::NameValue(ref meta_name_value) => match &meta_name_value.lit {
+            Lit::Str(ref s) => s
+                .parse::<Type>()
+                .map_err(|err| FromMetaError::TypeParseFailed(meta, s, err)),
+            lit => Err(FromMetaError::LitTypeMismatch(meta, lit)),
+        },
+        meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Meta, &'a Lit),
+    TypeParseFailed(&'a Meta, &'a LitStr, syn::Error),
+    DuplicateMeta(&'a Meta),
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/check.rs#L413-L420)
+    FromWithTryFrom(&'a Meta, &'a Meta),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message.
+    pub fn into_error(self) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(meta) => Error::new(
+                meta_span(meta),
+                format_args!(
+                    r#"malformed {0} attribute, expected `{0} = "..."`"#,
+                    meta_name(meta)
+                ),
+            ),
+            Self::LitTypeMismatch(meta, lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    meta_name(meta)
+                ),
+            ),
+            Self::TypeParseFailed(meta, s, _) => Error::new(
+                s.span(),
+                format_args!(
+                    "failed to parse type: {} = {:?}",
+                    meta_name(meta),
+                    s.value()
+                ),
+            ),
+            Self::DuplicateMeta(meta) => Error::new(
+                meta_span(meta),
+                format_args!("duplicate serde attribute `{}`", meta_name(meta)),
+            ),
+            Self::FromWithTryFrom(_, try_from_meta) => Error::new(
+                meta_span(try_from_meta),
+                r#"#[serde(from = "...")] and #[serde(try_from = "...")] conflict with each other"#,
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_, _) => write!(f, "LitTypeMismatch"),
+            Self::TypeParseFailed(_, _, err) => write!(f, "TypeParseFailed({})", err),
+            Self::DuplicateMeta(_) => write!(f, "DuplicateMeta"),
+            Self::FromWithTryFrom(_, _) => write!(f, "FromWithTryFrom"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
@@ -0,0 +1,35 @@
+//! [Official doc](https://serde.rs/container-attrs.html#crate)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use ::syn::Path;
+
+/// `#[serde(crate = "...")]`, the path serde is reachable at.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct CratePath(pub Path);
+impl CratePath {
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+/// `::serde`, what serde_derive emits without `#[serde(crate = "...")]`.
+impl Default for CratePath {
+    fn default() -> Self {
+        Self(::syn::parse_str("::serde").expect("`::serde` is a valid path"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ::syn::parse_quote;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(CratePath::default().path(), &parse_quote!(::serde));
+    }
+}
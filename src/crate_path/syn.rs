@@ -0,0 +1,75 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn::{Lit, LitStr, Meta, Path};
+
+use crate::{attrs::meta_span, Error};
+
+use super::CratePath;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L11)
+pub const CRATE: &str = "crate";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L556-L561)
+impl<'a> TryFrom<&'a Meta> for CratePath {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::NameValue(ref meta_name_value) if meta_name_value.path.is_ident(CRATE) => {
+                match &meta_name_value.lit {
+                    Lit::Str(ref s) => s
+                        .parse::<Path>()
+                        .map(Self)
+                        .map_err(|err| FromMetaError::PathParseFailed(s, err)),
+                    lit => Err(FromMetaError::LitTypeMismatch(lit)),
+                }
+            }
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Lit),
+    PathParseFailed(&'a LitStr, syn::Error),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1609-L1614)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(r#"malformed {0} attribute, expected `{0} = "..."`"#, CRATE),
+            ),
+            Self::LitTypeMismatch(lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    CRATE
+                ),
+            ),
+            Self::PathParseFailed(s, _) => Error::new(
+                s.span(),
+                format_args!("failed to parse path: {:?}", s.value()),
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+            Self::PathParseFailed(_, err) => write!(f, "PathParseFailed({})", err),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
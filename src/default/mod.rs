@@ -0,0 +1,25 @@
+//! [Official doc](https://serde.rs/field-attrs.html#default)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use ::syn::ExprPath;
+
+/// `#[serde(default)]` or `#[serde(default = "path::to::fn")]`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Default {
+    /// Use `Default::default()`.
+    Default,
+    /// Call the given function.
+    Path(ExprPath),
+}
+impl Default {
+    pub fn path(&self) -> Option<&ExprPath> {
+        match self {
+            Self::Default => None,
+            Self::Path(path) => Some(path),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn::{ExprPath, Lit, LitStr, Meta};
+
+use crate::{attrs::meta_span, Error};
+
+use super::Default;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L12)
+pub const DEFAULT: &str = "default";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1210-L1220)
+impl<'a> TryFrom<&'a Meta> for Default {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::Path(ref path) if path.is_ident(DEFAULT) => Ok(Self::Default),
+            Meta::NameValue(ref meta_name_value) if meta_name_value.path.is_ident(DEFAULT) => {
+                match &meta_name_value.lit {
+                    Lit::Str(ref s) => s
+                        .parse::<ExprPath>()
+                        .map(Self::Path)
+                        .map_err(|err| FromMetaError::ExprPathParseFailed(s, err)),
+                    lit => Err(FromMetaError::LitTypeMismatch(lit)),
+                }
+            }
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Lit),
+    ExprPathParseFailed(&'a LitStr, syn::Error),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1616-L1625)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(
+                    r#"malformed {0} attribute, expected `{0}` or `{0} = "..."`"#,
+                    DEFAULT
+                ),
+            ),
+            Self::LitTypeMismatch(lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    DEFAULT
+                ),
+            ),
+            Self::ExprPathParseFailed(s, _) => Error::new(
+                s.span(),
+                format_args!("failed to parse path: {:?}", s.value()),
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+            Self::ExprPathParseFailed(_, err) => write!(f, "ExprPathParseFailed({})", err),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
@@ -0,0 +1,10 @@
+//! [Official doc](https://serde.rs/container-attrs.html#deny_unknown_fields)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+/// `#[serde(deny_unknown_fields)]`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct DenyUnknownFields;
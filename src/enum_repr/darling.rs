@@ -0,0 +1,57 @@
+use std::convert::TryFrom;
+
+use darling_core::{Error as DarlingError, FromMeta};
+use syn::{Meta, NestedMeta};
+
+use super::{syn::FromMetaError, EnumRepr, Untagged};
+
+/// Parses the items of a `#[serde(...)]` list, e.g. `#[serde(tag = "t", content = "c")]`.
+impl FromMeta for EnumRepr {
+    fn from_list(items: &[NestedMeta]) -> Result<Self, DarlingError> {
+        let metas = items
+            .iter()
+            .map(|nested_meta| match nested_meta {
+                NestedMeta::Meta(meta) => Ok(meta.to_owned()),
+                NestedMeta::Lit(lit) => Err(DarlingError::unexpected_lit_type(lit)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::try_from(&metas[..]).map_err(into_darling_error)
+    }
+}
+
+impl FromMeta for Untagged {
+    fn from_meta(meta: &Meta) -> Result<Self, DarlingError> {
+        Self::try_from(meta).map_err(|err| match err {
+            FromMetaError::MetaTypeOrPathMismatch(Meta::Path(path)) => {
+                DarlingError::unknown_field_path(path)
+            }
+            err @ FromMetaError::MetaTypeOrPathMismatch(_)
+            | err @ FromMetaError::LitTypeMismatch(_, _)
+            | err @ FromMetaError::DuplicateMeta(_)
+            | err @ FromMetaError::ContentWithoutTag(_)
+            | err @ FromMetaError::UntaggedWithTag(_, _)
+            | err @ FromMetaError::UntaggedWithContent(_, _)
+            | err @ FromMetaError::UntaggedWithTagAndContent(_, _, _) => into_darling_error(err),
+        })
+    }
+}
+
+/// The conflicts between `tag`, `content` and `untagged` keep serde's message.
+fn into_darling_error(err: FromMetaError<'_>) -> DarlingError {
+    match err {
+        FromMetaError::MetaTypeOrPathMismatch(meta) => match meta {
+            Meta::Path(_) => DarlingError::unexpected_type("Meta::Path"),
+            Meta::List(_) => DarlingError::unexpected_type("Meta::List"),
+            Meta::NameValue(_) => DarlingError::unexpected_type("Meta::NameValue"),
+        },
+        FromMetaError::LitTypeMismatch(_, lit) => DarlingError::unexpected_lit_type(lit),
+        FromMetaError::DuplicateMeta(meta) => DarlingError::duplicate_field_path(meta.path()),
+        err @ FromMetaError::ContentWithoutTag(_)
+        | err @ FromMetaError::UntaggedWithTag(_, _)
+        | err @ FromMetaError::UntaggedWithContent(_, _)
+        | err @ FromMetaError::UntaggedWithTagAndContent(_, _, _) => {
+            DarlingError::custom(err.into_error())
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! [Official doc](https://serde.rs/enum-representations.html)
+
+use std::{error, fmt};
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+/// How serde lays out an enum, decided by `tag`, `content` and `untagged`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Default)]
+pub enum EnumRepr {
+    /// The default, `{"Variant": {...}}`.
+    #[default]
+    External,
+    /// `#[serde(tag = "...")]`, `{"tag": "Variant", ...}`.
+    Internal { tag: String },
+    /// `#[serde(tag = "...", content = "...")]`, `{"tag": "Variant", "content": {...}}`.
+    Adjacent { tag: String, content: String },
+    /// `#[serde(untagged)]`, `{...}`.
+    Untagged,
+}
+impl EnumRepr {
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L706-L786)
+    pub fn from_parts(
+        tag: Option<String>,
+        content: Option<String>,
+        untagged: bool,
+    ) -> Result<Self, FromPartsError> {
+        match (untagged, tag, content) {
+            (false, None, None) => Ok(Self::External),
+            (true, None, None) => Ok(Self::Untagged),
+            (false, Some(tag), None) => Ok(Self::Internal { tag }),
+            (false, Some(tag), Some(content)) => Ok(Self::Adjacent { tag, content }),
+            (false, None, Some(_)) => Err(FromPartsError::ContentWithoutTag),
+            (true, Some(_), None) => Err(FromPartsError::UntaggedWithTag),
+            (true, None, Some(_)) => Err(FromPartsError::UntaggedWithContent),
+            (true, Some(_), Some(_)) => Err(FromPartsError::UntaggedWithTagAndContent),
+        }
+    }
+
+    pub fn tag(&self) -> Option<&str> {
+        match self {
+            Self::Internal { tag } | Self::Adjacent { tag, content: _ } => Some(tag),
+            _ => None,
+        }
+    }
+
+    pub fn content(&self) -> Option<&str> {
+        match self {
+            Self::Adjacent { tag: _, content } => Some(content),
+            _ => None,
+        }
+    }
+}
+
+/// `#[serde(untagged)]` on a single variant of an otherwise tagged enum.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L878-L882)
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct Untagged;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FromPartsError {
+    ContentWithoutTag,
+    UntaggedWithTag,
+    UntaggedWithContent,
+    UntaggedWithTagAndContent,
+}
+impl fmt::Display for FromPartsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl error::Error for FromPartsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_parts() {
+        assert_eq!(
+            EnumRepr::from_parts(None, None, false),
+            Ok(EnumRepr::External)
+        );
+        assert_eq!(
+            EnumRepr::from_parts(None, None, true),
+            Ok(EnumRepr::Untagged)
+        );
+        assert_eq!(
+            EnumRepr::from_parts(Some("t".to_owned()), None, false),
+            Ok(EnumRepr::Internal {
+                tag: "t".to_owned()
+            })
+        );
+        assert_eq!(
+            EnumRepr::from_parts(Some("t".to_owned()), Some("c".to_owned()), false),
+            Ok(EnumRepr::Adjacent {
+                tag: "t".to_owned(),
+                content: "c".to_owned()
+            })
+        );
+
+        assert_eq!(
+            EnumRepr::from_parts(None, Some("c".to_owned()), false),
+            Err(FromPartsError::ContentWithoutTag)
+        );
+        assert_eq!(
+            EnumRepr::from_parts(Some("t".to_owned()), None, true),
+            Err(FromPartsError::UntaggedWithTag)
+        );
+        assert_eq!(
+            EnumRepr::from_parts(Some("t".to_owned()), Some("c".to_owned()), true),
+            Err(FromPartsError::UntaggedWithTagAndContent)
+        );
+        assert_eq!(
+            EnumRepr::from_parts(None, Some("c".to_owned()), true),
+            Err(FromPartsError::UntaggedWithContent)
+        );
+    }
+}
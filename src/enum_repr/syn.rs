@@ -0,0 +1,194 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn::{DataEnum, Fields, Lit, Meta};
+
+use crate::{
+    attrs::{meta_name, meta_span},
+    Error,
+};
+
+use super::{EnumRepr, Untagged};
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L32)
+pub const TAG: &str = "tag";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L10)
+pub const CONTENT: &str = "content";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L35)
+pub const UNTAGGED: &str = "untagged";
+
+/// Takes the metas of a container's `#[serde(...)]` lists, ignoring the ones that are not
+/// `tag`, `content` or `untagged`.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L444-L512)
+impl<'a> TryFrom<&'a [Meta]> for EnumRepr {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(metas: &'a [Meta]) -> Result<Self, Self::Error> {
+        let mut tag: Option<(&'a Meta, String)> = None;
+        let mut content: Option<(&'a Meta, String)> = None;
+        let mut untagged: Option<&'a Meta> = None;
+
+        for meta in metas {
+            if meta.path().is_ident(TAG) {
+                let value = get_lit_str(meta)?;
+                if tag.is_some() {
+                    return Err(FromMetaError::DuplicateMeta(meta));
+                }
+                tag = Some((meta, value));
+            } else if meta.path().is_ident(CONTENT) {
+                let value = get_lit_str(meta)?;
+                if content.is_some() {
+                    return Err(FromMetaError::DuplicateMeta(meta));
+                }
+                content = Some((meta, value));
+            } else if meta.path().is_ident(UNTAGGED) {
+                if !matches!(meta, Meta::Path(_)) {
+                    return Err(FromMetaError::MetaTypeOrPathMismatch(meta));
+                }
+                if untagged.is_some() {
+                    return Err(FromMetaError::DuplicateMeta(meta));
+                }
+                untagged = Some(meta);
+            }
+        }
+
+        // Mirrors `EnumRepr::from_parts`, keeping the metas so errors can point at them.
+        match (untagged, tag, content) {
+            (None, None, None) => Ok(EnumRepr::External),
+            (Some(_), None, None) => Ok(EnumRepr::Untagged),
+            (None, Some((_, tag)), None) => Ok(EnumRepr::Internal { tag }),
+            (None, Some((_, tag)), Some((_, content))) => Ok(EnumRepr::Adjacent { tag, content }),
+            (None, None, Some((content_meta, _))) => {
+                Err(FromMetaError::ContentWithoutTag(content_meta))
+            }
+            (Some(untagged_meta), Some((tag_meta, _)), None) => {
+                Err(FromMetaError::UntaggedWithTag(untagged_meta, tag_meta))
+            }
+            (Some(untagged_meta), None, Some((content_meta, _))) => Err(
+                FromMetaError::UntaggedWithContent(untagged_meta, content_meta),
+            ),
+            (Some(untagged_meta), Some((tag_meta, _)), Some((content_meta, _))) => Err(
+                FromMetaError::UntaggedWithTagAndContent(untagged_meta, tag_meta, content_meta),
+            ),
+        }
+    }
+}
+
+impl EnumRepr {
+    /// Checks that an internally tagged enum has no tuple variants, whose fields have no name
+    /// to put beside the tag.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L719-L737)
+    pub fn check_variants(&self, data_enum: &DataEnum) -> Result<(), Error> {
+        if let Self::Internal { .. } = self {
+            if let Some(variant) = data_enum.variants.iter().find(|variant| {
+                matches!(&variant.fields, Fields::Unnamed(fields) if fields.unnamed.len() != 1)
+            }) {
+                return Err(Error::new(
+                    variant.ident.span(),
+                    r#"#[serde(tag = "...")] cannot be used with tuple variants"#,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L878-L882)
+impl<'a> TryFrom<&'a Meta> for Untagged {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::Path(ref path) if path.is_ident(UNTAGGED) => Ok(Self),
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+fn get_lit_str(meta: &Meta) -> Result<String, FromMetaError<'_>> {
+    match meta {
+        Meta::NameValue(ref meta_name_value) => match &meta_name_value.lit {
+            Lit::Str(ref s) => Ok(s.value()),
+            lit => Err(FromMetaError::LitTypeMismatch(meta, lit)),
+        },
+        meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Meta, &'a Lit),
+    DuplicateMeta(&'a Meta),
+    ContentWithoutTag(&'a Meta),
+    UntaggedWithTag(&'a Meta, &'a Meta),
+    UntaggedWithContent(&'a Meta, &'a Meta),
+    /// The `untagged`, `tag` and `content` metas.
+    UntaggedWithTagAndContent(&'a Meta, &'a Meta, &'a Meta),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L706-L786)
+    pub fn into_error(self) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(meta) if meta.path().is_ident(UNTAGGED) => Error::new(
+                meta_span(meta),
+                format_args!("malformed {0} attribute, expected `{0}`", UNTAGGED),
+            ),
+            Self::MetaTypeOrPathMismatch(meta) => Error::new(
+                meta_span(meta),
+                format_args!(
+                    r#"malformed {0} attribute, expected `{0} = "..."`"#,
+                    meta_name(meta)
+                ),
+            ),
+            Self::LitTypeMismatch(meta, lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    meta_name(meta)
+                ),
+            ),
+            Self::DuplicateMeta(meta) => Error::new(
+                meta_span(meta),
+                format_args!("duplicate serde attribute `{}`", meta_name(meta)),
+            ),
+            Self::ContentWithoutTag(content_meta) => Error::new(
+                meta_span(content_meta),
+                r#"#[serde(tag = "...", content = "...")] must be used together"#,
+            ),
+            Self::UntaggedWithTag(untagged_meta, _) => Error::new(
+                meta_span(untagged_meta),
+                "enum cannot be both untagged and internally tagged",
+            ),
+            Self::UntaggedWithContent(untagged_meta, _) => Error::new(
+                meta_span(untagged_meta),
+                r#"untagged enum cannot have #[serde(content = "...")]"#,
+            ),
+            Self::UntaggedWithTagAndContent(untagged_meta, _, _) => Error::new(
+                meta_span(untagged_meta),
+                r#"untagged enum cannot have #[serde(tag = "...", content = "...")]"#,
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_, _) => write!(f, "LitTypeMismatch"),
+            Self::DuplicateMeta(_) => write!(f, "DuplicateMeta"),
+            Self::ContentWithoutTag(_) => write!(f, "ContentWithoutTag"),
+            Self::UntaggedWithTag(_, _) => write!(f, "UntaggedWithTag"),
+            Self::UntaggedWithContent(_, _) => write!(f, "UntaggedWithContent"),
+            Self::UntaggedWithTagAndContent(_, _, _) => write!(f, "UntaggedWithTagAndContent"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
@@ -0,0 +1,134 @@
+//! An owned error that points at the offending `#[serde(...)]` tokens.
+
+use std::{error, fmt};
+
+use proc_macro2::{Span, TokenStream};
+
+/// One or more attribute problems, each with a `Span` and a serde-style message.
+///
+/// [`combine`](Error::combine) them while walking an item, then turn the result into a
+/// `syn::Error` with `with-syn`, a syn 2 one with `with-syn2`, or straight into `compile_error!`
+/// tokens so every problem is reported at once.
+pub struct Error {
+    messages: Vec<(Span, String)>,
+}
+impl Error {
+    pub fn new<T: fmt::Display>(span: Span, message: T) -> Self {
+        Self {
+            messages: vec![(span, message.to_string())],
+        }
+    }
+
+    /// The span of the first problem.
+    pub fn span(&self) -> Span {
+        self.messages[0].0
+    }
+
+    /// Appends `other`'s problems after this one's.
+    pub fn combine(&mut self, other: Self) {
+        self.messages.extend(other.messages)
+    }
+
+    /// One `compile_error!` per problem.
+    #[cfg(feature = "with-syn")]
+    pub fn to_compile_error(&self) -> TokenStream {
+        syn::Error::from(self).to_compile_error()
+    }
+
+    /// One `compile_error!` per problem.
+    #[cfg(not(feature = "with-syn"))]
+    pub fn to_compile_error(&self) -> TokenStream {
+        syn2::Error::from(self).to_compile_error()
+    }
+}
+/// Keeps every message of a combined `syn::Error`.
+#[cfg(feature = "with-syn")]
+impl From<syn::Error> for Error {
+    fn from(err: syn::Error) -> Self {
+        Self {
+            messages: err
+                .into_iter()
+                .map(|err| (err.span(), err.to_string()))
+                .collect(),
+        }
+    }
+}
+#[cfg(feature = "with-syn")]
+impl From<&Error> for syn::Error {
+    fn from(err: &Error) -> Self {
+        let mut messages = err.messages.iter();
+        let (span, message) = messages.next().expect("at least one message");
+        let mut syn_err = syn::Error::new(*span, message);
+        for (span, message) in messages {
+            syn_err.combine(syn::Error::new(*span, message));
+        }
+        syn_err
+    }
+}
+#[cfg(feature = "with-syn")]
+impl From<Error> for syn::Error {
+    fn from(err: Error) -> Self {
+        Self::from(&err)
+    }
+}
+#[cfg(feature = "with-syn2")]
+impl From<&Error> for syn2::Error {
+    fn from(err: &Error) -> Self {
+        let mut messages = err.messages.iter();
+        let (span, message) = messages.next().expect("at least one message");
+        let mut syn_err = syn2::Error::new(*span, message);
+        for (span, message) in messages {
+            syn_err.combine(syn2::Error::new(*span, message));
+        }
+        syn_err
+    }
+}
+#[cfg(feature = "with-syn2")]
+impl From<Error> for syn2::Error {
+    fn from(err: Error) -> Self {
+        Self::from(&err)
+    }
+}
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.messages.iter().map(|(_, message)| message))
+            .finish()
+    }
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.messages[0].1)
+    }
+}
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combine() {
+        let mut err = Error::new(Span::call_site(), "a");
+        err.combine(Error::new(Span::call_site(), "b"));
+        assert_eq!(err.to_string(), "a");
+        assert_eq!(format!("{:?}", err), r#"["a", "b"]"#);
+
+        #[cfg(feature = "with-syn")]
+        assert_eq!(
+            syn::Error::from(&err)
+                .into_iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+        #[cfg(feature = "with-syn2")]
+        assert_eq!(
+            syn2::Error::from(&err)
+                .into_iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+    }
+}
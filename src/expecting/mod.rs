@@ -0,0 +1,10 @@
+//! [Official doc](https://serde.rs/container-attrs.html#expecting)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+/// `#[serde(expecting = "...")]`, the text of the `Visitor::expecting` error message.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Expecting(pub String);
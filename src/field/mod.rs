@@ -0,0 +1,45 @@
+//! [Official doc](https://serde.rs/field-attrs.html)
+
+pub mod syn;
+
+#[cfg(feature = "attr-default")]
+use crate::default::Default;
+#[cfg(feature = "attr-alias")]
+use crate::Alias;
+#[cfg(feature = "attr-borrow")]
+use crate::Borrow;
+#[cfg(feature = "attr-bound")]
+use crate::Bound;
+#[cfg(feature = "attr-flatten")]
+use crate::Flatten;
+#[cfg(feature = "attr-getter")]
+use crate::Getter;
+#[cfg(feature = "attr-rename")]
+use crate::Rename;
+#[cfg(feature = "attr-skip")]
+use crate::Skip;
+#[cfg(feature = "attr-with")]
+use crate::With;
+
+/// All `#[serde(...)]` attributes of a struct or variant field.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Field {
+    #[cfg(feature = "attr-rename")]
+    pub rename: Option<Rename>,
+    #[cfg(feature = "attr-alias")]
+    pub alias: Vec<Alias>,
+    #[cfg(feature = "attr-default")]
+    pub default: Option<Default>,
+    #[cfg(feature = "attr-skip")]
+    pub skip: Option<Skip>,
+    #[cfg(feature = "attr-with")]
+    pub with: Option<With>,
+    #[cfg(feature = "attr-flatten")]
+    pub flatten: Option<Flatten>,
+    #[cfg(feature = "attr-bound")]
+    pub bound: Option<Bound>,
+    #[cfg(feature = "attr-borrow")]
+    pub borrow: Option<Borrow>,
+    #[cfg(feature = "attr-getter")]
+    pub getter: Option<Getter>,
+}
@@ -0,0 +1,195 @@
+use std::convert::TryFrom;
+
+use syn::{Attribute, Field as SynField};
+
+#[cfg(any(
+    feature = "attr-default",
+    feature = "attr-flatten",
+    feature = "attr-borrow",
+    feature = "attr-getter"
+))]
+use crate::attrs::set_once;
+#[cfg(any(feature = "attr-rename", feature = "attr-skip", feature = "attr-bound"))]
+use crate::attrs::set_or_merge;
+use crate::attrs::{ok_or_combined, parse_serde_meta_list, FromAttrsError};
+#[cfg(feature = "attr-default")]
+use crate::default::{syn::DEFAULT, Default};
+#[cfg(feature = "attr-with")]
+use crate::with::{
+    self,
+    syn::{DESERIALIZE_WITH, SERIALIZE_WITH, WITH},
+};
+#[cfg(feature = "attr-alias")]
+use crate::{alias::syn::ALIAS, Alias};
+#[cfg(feature = "attr-borrow")]
+use crate::{borrow::syn::BORROW, Borrow};
+#[cfg(feature = "attr-bound")]
+use crate::{bound::syn::BOUND, Bound};
+#[cfg(feature = "attr-flatten")]
+use crate::{flatten::syn::FLATTEN, Flatten};
+#[cfg(feature = "attr-getter")]
+use crate::{getter::syn::GETTER, Getter};
+#[cfg(feature = "attr-rename")]
+use crate::{rename::syn::RENAME, Rename};
+#[cfg(feature = "attr-skip")]
+use crate::{
+    skip::syn::{SKIP, SKIP_DESERIALIZING, SKIP_SERIALIZING, SKIP_SERIALIZING_IF},
+    Skip,
+};
+
+use super::Field;
+
+/// Every field attribute serde knows about.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1151-L1164)
+pub const FIELD_ATTRS: &[&str] = &[
+    "rename",
+    "alias",
+    "default",
+    "skip",
+    "skip_serializing",
+    "skip_deserializing",
+    "skip_serializing_if",
+    "with",
+    "serialize_with",
+    "deserialize_with",
+    "flatten",
+    "borrow",
+    "bound",
+    "getter",
+];
+
+impl Field {
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1144-L1367)
+    pub fn try_from_attrs(attrs: &[Attribute]) -> Result<Self, FromAttrsError> {
+        #[allow(unused_mut)]
+        let mut field = Self::default();
+        let mut errors = vec![];
+        #[cfg(feature = "attr-with")]
+        let mut with_metas = vec![];
+
+        for meta in parse_serde_meta_list(attrs)? {
+            #[cfg(feature = "attr-rename")]
+            if meta.path().is_ident(RENAME) {
+                let result = match Rename::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(rename) => set_or_merge(&mut field.rename, rename, meta, Rename::merge),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-alias")]
+            if meta.path().is_ident(ALIAS) {
+                let result = match Alias::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(alias) => {
+                        field.alias.push(alias);
+                        Ok(())
+                    }
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-default")]
+            if meta.path().is_ident(DEFAULT) {
+                let result = match Default::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(default) => set_once(&mut field.default, default, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-skip")]
+            if [
+                SKIP,
+                SKIP_SERIALIZING,
+                SKIP_DESERIALIZING,
+                SKIP_SERIALIZING_IF,
+            ]
+            .iter()
+            .any(|name| meta.path().is_ident(name))
+            {
+                let result = match Skip::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(skip) => set_or_merge(&mut field.skip, skip, meta, Skip::merge),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-with")]
+            if [WITH, SERIALIZE_WITH, DESERIALIZE_WITH]
+                .iter()
+                .any(|name| meta.path().is_ident(name))
+            {
+                with_metas.push(meta);
+                continue;
+            }
+
+            #[cfg(feature = "attr-flatten")]
+            if meta.path().is_ident(FLATTEN) {
+                let result = match Flatten::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(flatten) => set_once(&mut field.flatten, flatten, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-bound")]
+            if meta.path().is_ident(BOUND) {
+                let result = match Bound::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(bound) => set_or_merge(&mut field.bound, bound, meta, Bound::merge),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-borrow")]
+            if meta.path().is_ident(BORROW) {
+                let result = match Borrow::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(borrow) => set_once(&mut field.borrow, borrow, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-getter")]
+            if meta.path().is_ident(GETTER) {
+                let result = match Getter::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(getter) => set_once(&mut field.getter, getter, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            if !FIELD_ATTRS.iter().any(|name| meta.path().is_ident(name)) {
+                errors.push(FromAttrsError::UnknownAttribute(meta));
+            }
+        }
+
+        #[cfg(feature = "attr-with")]
+        {
+            match with::syn::try_from_attr_metas(&with_metas) {
+                Ok(with) => field.with = with,
+                Err(err) => errors.push(err),
+            }
+        }
+
+        ok_or_combined(field, errors)
+    }
+}
+
+impl<'a> TryFrom<&'a SynField> for Field {
+    type Error = FromAttrsError;
+
+    fn try_from(field: &'a SynField) -> Result<Self, Self::Error> {
+        Self::try_from_attrs(&field.attrs)
+    }
+}
@@ -0,0 +1,18 @@
+use std::convert::TryFrom;
+
+use darling_core::{Error as DarlingError, FromMeta};
+use syn::Meta;
+
+use super::{syn::FromMetaError, Flatten};
+
+impl FromMeta for Flatten {
+    fn from_meta(meta: &Meta) -> Result<Self, DarlingError> {
+        Self::try_from(meta).map_err(|err| match err {
+            FromMetaError::MetaTypeOrPathMismatch(meta) => match meta {
+                Meta::Path(path) => DarlingError::unknown_field_path(path),
+                Meta::List(_) => DarlingError::unexpected_type("Meta::List"),
+                Meta::NameValue(_) => DarlingError::unexpected_type("Meta::NameValue"),
+            },
+        })
+    }
+}
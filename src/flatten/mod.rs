@@ -0,0 +1,10 @@
+//! [Official doc](https://serde.rs/field-attrs.html#flatten)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+/// `#[serde(flatten)]`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct Flatten;
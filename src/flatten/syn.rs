@@ -0,0 +1,194 @@
+use std::{convert::TryFrom, error, fmt};
+
+use proc_macro2::Span;
+use syn::{Data, DeriveInput, Fields, Meta};
+
+use crate::{
+    attrs::{meta_span, parse_serde_meta_list, FromAttrsError, SKIP_ATTRS},
+    deny_unknown_fields::syn::DENY_UNKNOWN_FIELDS,
+    Error,
+};
+
+use super::Flatten;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L17)
+pub const FLATTEN: &str = "flatten";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L31)
+const SKIP_SERIALIZING_IF: &str = "skip_serializing_if";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1327-L1330)
+impl<'a> TryFrom<&'a Meta> for Flatten {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::Path(ref path) if path.is_ident(FLATTEN) => Ok(Self),
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(r#"malformed {0} attribute, expected `{0}`"#, FLATTEN),
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
+
+/// Flags the `flatten` fields serde rejects or cannot honor, stopping at the first one.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/check.rs#L43-L79)
+pub fn check_flatten(input: &DeriveInput) -> Result<(), CheckFlattenError> {
+    let deny_unknown_fields = parse_serde_meta_list(&input.attrs)?
+        .into_iter()
+        .find(|meta| meta.path().is_ident(DENY_UNKNOWN_FIELDS));
+
+    let fields_list: Vec<&Fields> = match &input.data {
+        Data::Struct(data_struct) => vec![&data_struct.fields],
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| &variant.fields)
+            .collect(),
+        Data::Union(_) => vec![],
+    };
+
+    for fields in fields_list {
+        for field in fields {
+            let metas = parse_serde_meta_list(&field.attrs)?;
+            let flatten = match metas.iter().find(|meta| meta.path().is_ident(FLATTEN)) {
+                Some(meta) => meta_span(meta),
+                None => continue,
+            };
+
+            if let Fields::Unnamed(fields_unnamed) = fields {
+                return Err(CheckFlattenError::InTuple {
+                    flatten,
+                    tuple: fields_unnamed.paren_token.span,
+                });
+            }
+            if let Some(skip) = metas
+                .iter()
+                .find(|meta| SKIP_ATTRS.iter().any(|name| meta.path().is_ident(name)))
+            {
+                return Err(CheckFlattenError::WithSkip {
+                    flatten,
+                    skip: meta_span(skip),
+                });
+            }
+            if let Some(skip_serializing_if) = metas
+                .iter()
+                .find(|meta| meta.path().is_ident(SKIP_SERIALIZING_IF))
+            {
+                return Err(CheckFlattenError::WithSkipSerializingIf {
+                    flatten,
+                    skip_serializing_if: meta_span(skip_serializing_if),
+                });
+            }
+            if let Some(deny_unknown_fields) = &deny_unknown_fields {
+                return Err(CheckFlattenError::WithDenyUnknownFields {
+                    flatten,
+                    deny_unknown_fields: meta_span(deny_unknown_fields),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Every span points at the offending attribute, or at the parentheses of the tuple fields.
+pub enum CheckFlattenError {
+    FromAttrsFailed(FromAttrsError),
+    /// `flatten` on a field of a tuple struct or tuple variant.
+    InTuple {
+        flatten: Span,
+        tuple: Span,
+    },
+    /// `flatten` together with `skip`, `skip_serializing` or `skip_deserializing`.
+    WithSkip {
+        flatten: Span,
+        skip: Span,
+    },
+    /// `flatten` together with `skip_serializing_if`.
+    WithSkipSerializingIf {
+        flatten: Span,
+        skip_serializing_if: Span,
+    },
+    /// `flatten` in a container with `deny_unknown_fields`, which serde does not support.
+    WithDenyUnknownFields {
+        flatten: Span,
+        deny_unknown_fields: Span,
+    },
+}
+impl From<FromAttrsError> for CheckFlattenError {
+    fn from(err: FromAttrsError) -> Self {
+        Self::FromAttrsFailed(err)
+    }
+}
+impl fmt::Debug for CheckFlattenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "FromAttrsFailed({:?})", err),
+            Self::InTuple { .. } => write!(f, "InTuple"),
+            Self::WithSkip { .. } => write!(f, "WithSkip"),
+            Self::WithSkipSerializingIf { .. } => write!(f, "WithSkipSerializingIf"),
+            Self::WithDenyUnknownFields { .. } => write!(f, "WithDenyUnknownFields"),
+        }
+    }
+}
+impl fmt::Display for CheckFlattenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "{}", err),
+            Self::InTuple { .. } => write!(f, "#[serde(flatten)] cannot be used on tuple fields"),
+            Self::WithSkip { .. } => write!(
+                f,
+                "#[serde(flatten)] cannot be combined with #[serde(skip_*)]"
+            ),
+            Self::WithSkipSerializingIf { .. } => write!(
+                f,
+                r#"#[serde(flatten)] can not be combined with #[serde(skip_serializing_if = "...")]"#
+            ),
+            Self::WithDenyUnknownFields { .. } => write!(
+                f,
+                "#[serde(flatten)] is not supported together with #[serde(deny_unknown_fields)]"
+            ),
+        }
+    }
+}
+impl error::Error for CheckFlattenError {}
+/// Points at the `flatten` attribute.
+impl From<CheckFlattenError> for Error {
+    fn from(err: CheckFlattenError) -> Self {
+        match err {
+            CheckFlattenError::FromAttrsFailed(err) => Self::from(err),
+            CheckFlattenError::InTuple { flatten, .. }
+            | CheckFlattenError::WithSkip { flatten, .. }
+            | CheckFlattenError::WithSkipSerializingIf { flatten, .. }
+            | CheckFlattenError::WithDenyUnknownFields { flatten, .. } => Self::new(flatten, err),
+        }
+    }
+}
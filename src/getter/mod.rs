@@ -0,0 +1,17 @@
+//! [Official doc](https://serde.rs/field-attrs.html#getter)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use ::syn::ExprPath;
+
+/// `#[serde(getter = "...")]`, how a remote derive reads a private field.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Getter(pub ExprPath);
+impl Getter {
+    pub fn path(&self) -> &ExprPath {
+        &self.0
+    }
+}
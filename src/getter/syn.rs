@@ -0,0 +1,169 @@
+use std::{convert::TryFrom, error, fmt};
+
+use proc_macro2::Span;
+use syn::{Data, DeriveInput, ExprPath, Fields, Lit, LitStr, Meta};
+
+use crate::{
+    attrs::{meta_span, parse_serde_meta_list, FromAttrsError},
+    remote::syn::REMOTE,
+    Error,
+};
+
+use super::Getter;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L19)
+pub const GETTER: &str = "getter";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1320-L1325)
+impl<'a> TryFrom<&'a Meta> for Getter {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::NameValue(ref meta_name_value) if meta_name_value.path.is_ident(GETTER) => {
+                match &meta_name_value.lit {
+                    Lit::Str(ref s) => s
+                        .parse::<ExprPath>()
+                        .map(Self)
+                        .map_err(|err| FromMetaError::ExprPathParseFailed(s, err)),
+                    lit => Err(FromMetaError::LitTypeMismatch(lit)),
+                }
+            }
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Lit),
+    ExprPathParseFailed(&'a LitStr, syn::Error),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1616-L1625)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(r#"malformed {0} attribute, expected `{0} = "..."`"#, GETTER),
+            ),
+            Self::LitTypeMismatch(lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    GETTER
+                ),
+            ),
+            Self::ExprPathParseFailed(s, _) => Error::new(
+                s.span(),
+                format_args!("failed to parse path: {:?}", s.value()),
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+            Self::ExprPathParseFailed(_, err) => write!(f, "ExprPathParseFailed({})", err),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
+
+/// Flags a `getter` outside of a struct with `remote`, stopping at the first one.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/check.rs#L21-L40)
+pub fn check_getter(input: &DeriveInput) -> Result<(), CheckGetterError> {
+    let fields_list: Vec<&Fields> = match &input.data {
+        Data::Struct(data_struct) => vec![&data_struct.fields],
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| &variant.fields)
+            .collect(),
+        Data::Union(_) => vec![],
+    };
+
+    let mut getter = None;
+    'fields: for fields in fields_list {
+        for field in fields {
+            if let Some(meta) = parse_serde_meta_list(&field.attrs)?
+                .iter()
+                .find(|meta| meta.path().is_ident(GETTER))
+            {
+                getter = Some(meta_span(meta));
+                break 'fields;
+            }
+        }
+    }
+    let getter = match getter {
+        Some(getter) => getter,
+        None => return Ok(()),
+    };
+
+    match &input.data {
+        Data::Struct(_) => {
+            let remote = parse_serde_meta_list(&input.attrs)?
+                .iter()
+                .any(|meta| meta.path().is_ident(REMOTE));
+            if !remote {
+                return Err(CheckGetterError::WithoutRemote(getter));
+            }
+            Ok(())
+        }
+        Data::Enum(_) | Data::Union(_) => Err(CheckGetterError::NotAStruct(getter)),
+    }
+}
+
+/// The `Span` points at the first `getter` attribute.
+pub enum CheckGetterError {
+    FromAttrsFailed(FromAttrsError),
+    NotAStruct(Span),
+    WithoutRemote(Span),
+}
+impl From<FromAttrsError> for CheckGetterError {
+    fn from(err: FromAttrsError) -> Self {
+        Self::FromAttrsFailed(err)
+    }
+}
+impl fmt::Debug for CheckGetterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "FromAttrsFailed({:?})", err),
+            Self::NotAStruct(_) => write!(f, "NotAStruct"),
+            Self::WithoutRemote(_) => write!(f, "WithoutRemote"),
+        }
+    }
+}
+impl fmt::Display for CheckGetterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "{}", err),
+            Self::NotAStruct(_) => write!(f, "#[serde(getter = \"...\")] is not allowed in an enum"),
+            Self::WithoutRemote(_) => write!(
+                f,
+                "#[serde(getter = \"...\")] can only be used in structs that have #[serde(remote = \"...\")]"
+            ),
+        }
+    }
+}
+impl error::Error for CheckGetterError {}
+impl From<CheckGetterError> for Error {
+    fn from(err: CheckGetterError) -> Self {
+        match err {
+            CheckGetterError::FromAttrsFailed(err) => Self::from(err),
+            CheckGetterError::NotAStruct(span) | CheckGetterError::WithoutRemote(span) => {
+                Self::new(span, err)
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+
+use darling_core::{Error as DarlingError, FromMeta};
+use syn::{Meta, NestedMeta};
+
+use super::{syn::FromMetaError, Identifier, Other};
+
+/// Parses the items of a `#[serde(...)]` list, e.g. `#[serde(field_identifier)]`.
+impl FromMeta for Identifier {
+    fn from_list(items: &[NestedMeta]) -> Result<Self, DarlingError> {
+        let metas = items
+            .iter()
+            .map(|nested_meta| match nested_meta {
+                NestedMeta::Meta(meta) => Ok(meta.to_owned()),
+                NestedMeta::Lit(lit) => Err(DarlingError::unexpected_lit_type(lit)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::try_from(&metas[..]).map_err(into_darling_error)
+    }
+}
+
+impl FromMeta for Other {
+    fn from_meta(meta: &Meta) -> Result<Self, DarlingError> {
+        Self::try_from(meta).map_err(|err| match err {
+            FromMetaError::MetaTypeOrPathMismatch(Meta::Path(path)) => {
+                DarlingError::unknown_field_path(path)
+            }
+            err @ FromMetaError::MetaTypeOrPathMismatch(_)
+            | err @ FromMetaError::DuplicateMeta(_)
+            | err @ FromMetaError::FieldWithVariant(_, _) => into_darling_error(err),
+        })
+    }
+}
+
+fn into_darling_error(err: FromMetaError<'_>) -> DarlingError {
+    match err {
+        FromMetaError::MetaTypeOrPathMismatch(meta) => match meta {
+            Meta::Path(_) => DarlingError::unexpected_type("Meta::Path"),
+            Meta::List(_) => DarlingError::unexpected_type("Meta::List"),
+            Meta::NameValue(_) => DarlingError::unexpected_type("Meta::NameValue"),
+        },
+        FromMetaError::DuplicateMeta(meta) => DarlingError::duplicate_field_path(meta.path()),
+        FromMetaError::FieldWithVariant(_, _) => {
+            DarlingError::custom("field_identifier and variant_identifier cannot both be set")
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! [Official doc](https://serde.rs/container-attrs.html#variant_identifier)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+/// Whether an enum deserializes as the identifier of a field or a variant, decided by
+/// `field_identifier` and `variant_identifier`.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L261-L276)
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub enum Identifier {
+    /// The default, a regular enum.
+    #[default]
+    No,
+    /// `#[serde(field_identifier)]`, the enum names struct fields and may have a catch-all
+    /// `other` or newtype variant last.
+    Field,
+    /// `#[serde(variant_identifier)]`, the enum names enum variants.
+    Variant,
+}
+
+/// `#[serde(other)]`, the catch-all unit variant, see `syn::check_identifier`.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L956-L959)
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct Other;
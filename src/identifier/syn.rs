@@ -0,0 +1,274 @@
+use std::{convert::TryFrom, error, fmt};
+
+use proc_macro2::Span;
+use syn::{Data, DeriveInput, Fields, Meta};
+
+use crate::{
+    attrs::{meta_name, meta_span, parse_serde_meta_list, FromAttrsError},
+    Container, EnumRepr, Error,
+};
+
+use super::{Identifier, Other};
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L16)
+pub const FIELD_IDENTIFIER: &str = "field_identifier";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L36)
+pub const VARIANT_IDENTIFIER: &str = "variant_identifier";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L21)
+pub const OTHER: &str = "other";
+
+/// Takes the metas of a container's `#[serde(...)]` lists, ignoring the ones that are not
+/// `field_identifier` or `variant_identifier`.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L788-L842)
+impl<'a> TryFrom<&'a [Meta]> for Identifier {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(metas: &'a [Meta]) -> Result<Self, Self::Error> {
+        let mut field_identifier: Option<&'a Meta> = None;
+        let mut variant_identifier: Option<&'a Meta> = None;
+
+        for meta in metas {
+            let slot = if meta.path().is_ident(FIELD_IDENTIFIER) {
+                &mut field_identifier
+            } else if meta.path().is_ident(VARIANT_IDENTIFIER) {
+                &mut variant_identifier
+            } else {
+                continue;
+            };
+            if !matches!(meta, Meta::Path(_)) {
+                return Err(FromMetaError::MetaTypeOrPathMismatch(meta));
+            }
+            if slot.is_some() {
+                return Err(FromMetaError::DuplicateMeta(meta));
+            }
+            *slot = Some(meta);
+        }
+
+        match (field_identifier, variant_identifier) {
+            (None, None) => Ok(Self::No),
+            (Some(_), None) => Ok(Self::Field),
+            (None, Some(_)) => Ok(Self::Variant),
+            (Some(field_identifier), Some(variant_identifier)) => Err(
+                FromMetaError::FieldWithVariant(field_identifier, variant_identifier),
+            ),
+        }
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L956-L959)
+impl<'a> TryFrom<&'a Meta> for Other {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::Path(ref path) if path.is_ident(OTHER) => Ok(Self),
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    DuplicateMeta(&'a Meta),
+    FieldWithVariant(&'a Meta, &'a Meta),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L788-L842)
+    pub fn into_error(self) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(meta) => Error::new(
+                meta_span(meta),
+                format_args!("malformed {0} attribute, expected `{0}`", meta_name(meta)),
+            ),
+            Self::DuplicateMeta(meta) => Error::new(
+                meta_span(meta),
+                format_args!("duplicate serde attribute `{}`", meta_name(meta)),
+            ),
+            Self::FieldWithVariant(_, variant_identifier_meta) => Error::new(
+                meta_span(variant_identifier_meta),
+                "#[serde(field_identifier)] and #[serde(variant_identifier)] cannot both be set",
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::DuplicateMeta(_) => write!(f, "DuplicateMeta"),
+            Self::FieldWithVariant(_, _) => write!(f, "FieldWithVariant"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
+
+/// Flags the `other`, `field_identifier` and `variant_identifier` usages serde rejects,
+/// stopping at the first one.
+///
+/// On top of serde's checks, `other` is rejected more than once and in externally tagged
+/// enums, where serde ignores it.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/check.rs#L87-L168)
+pub fn check_identifier(input: &DeriveInput) -> Result<(), CheckIdentifierError> {
+    let container = Container::try_from_attrs(&input.attrs)?;
+    let identifier = container.identifier;
+
+    let variants = match &input.data {
+        Data::Enum(data_enum) => &data_enum.variants,
+        Data::Struct(_) | Data::Union(_) => {
+            return match parse_serde_meta_list(&input.attrs)?.iter().find(|meta| {
+                meta.path().is_ident(FIELD_IDENTIFIER) || meta.path().is_ident(VARIANT_IDENTIFIER)
+            }) {
+                Some(meta) => Err(CheckIdentifierError::NotAnEnum(meta_span(meta))),
+                None => Ok(()),
+            };
+        }
+    };
+
+    let others = variants
+        .iter()
+        .map(|variant| {
+            Ok(parse_serde_meta_list(&variant.attrs)?
+                .iter()
+                .find(|meta| meta.path().is_ident(OTHER))
+                .map(meta_span))
+        })
+        .collect::<Result<Vec<_>, FromAttrsError>>()?;
+    if let Some(other) = others.iter().flatten().nth(1) {
+        return Err(CheckIdentifierError::DuplicateOther(*other));
+    }
+
+    for (i, (variant, other)) in variants.iter().zip(others).enumerate() {
+        let is_last = i == variants.len() - 1;
+
+        if let Some(other) = other {
+            match (identifier, &container.enum_repr) {
+                (Identifier::Variant, _) => {
+                    return Err(CheckIdentifierError::OtherInVariantIdentifier(other))
+                }
+                (Identifier::No, EnumRepr::Untagged) => {
+                    return Err(CheckIdentifierError::OtherInUntagged(other))
+                }
+                (Identifier::No, EnumRepr::External) => {
+                    return Err(CheckIdentifierError::OtherInExternallyTagged(other))
+                }
+                _ => {}
+            }
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(CheckIdentifierError::OtherNotUnit(other));
+            }
+            if !is_last {
+                return Err(CheckIdentifierError::OtherNotLast(other));
+            }
+            continue;
+        }
+
+        let span = variant.ident.span();
+        match (&variant.fields, identifier) {
+            (_, Identifier::No) | (Fields::Unit, _) => {}
+            (Fields::Unnamed(fields), Identifier::Field) if fields.unnamed.len() == 1 => {
+                if !is_last {
+                    return Err(CheckIdentifierError::CatchAllNotLast(span));
+                }
+            }
+            (_, Identifier::Field) | (_, Identifier::Variant) => {
+                return Err(CheckIdentifierError::NotUnitVariant(span))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The `Span` points at the offending attribute, or at the variant's name.
+pub enum CheckIdentifierError {
+    FromAttrsFailed(FromAttrsError),
+    /// `field_identifier` or `variant_identifier` on a struct or union.
+    NotAnEnum(Span),
+    DuplicateOther(Span),
+    OtherInVariantIdentifier(Span),
+    OtherInUntagged(Span),
+    OtherInExternallyTagged(Span),
+    OtherNotUnit(Span),
+    OtherNotLast(Span),
+    /// The newtype variant a `field_identifier` may end with is not the last one.
+    CatchAllNotLast(Span),
+    /// A variant of an identifier enum that is neither unit nor the trailing newtype.
+    NotUnitVariant(Span),
+}
+impl From<FromAttrsError> for CheckIdentifierError {
+    fn from(err: FromAttrsError) -> Self {
+        Self::FromAttrsFailed(err)
+    }
+}
+impl fmt::Debug for CheckIdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "FromAttrsFailed({:?})", err),
+            Self::NotAnEnum(_) => write!(f, "NotAnEnum"),
+            Self::DuplicateOther(_) => write!(f, "DuplicateOther"),
+            Self::OtherInVariantIdentifier(_) => write!(f, "OtherInVariantIdentifier"),
+            Self::OtherInUntagged(_) => write!(f, "OtherInUntagged"),
+            Self::OtherInExternallyTagged(_) => write!(f, "OtherInExternallyTagged"),
+            Self::OtherNotUnit(_) => write!(f, "OtherNotUnit"),
+            Self::OtherNotLast(_) => write!(f, "OtherNotLast"),
+            Self::CatchAllNotLast(_) => write!(f, "CatchAllNotLast"),
+            Self::NotUnitVariant(_) => write!(f, "NotUnitVariant"),
+        }
+    }
+}
+impl fmt::Display for CheckIdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "{}", err),
+            Self::NotAnEnum(_) => write!(
+                f,
+                "#[serde(field_identifier)] and #[serde(variant_identifier)] can only be used on an enum"
+            ),
+            Self::DuplicateOther(_) => write!(f, "#[serde(other)] may only be used once"),
+            Self::OtherInVariantIdentifier(_) => {
+                write!(f, "#[serde(other)] may not be used on a variant identifier")
+            }
+            Self::OtherInUntagged(_) => write!(f, "#[serde(other)] cannot appear on untagged enum"),
+            Self::OtherInExternallyTagged(_) => write!(
+                f,
+                "#[serde(other)] may only be used in internally or adjacently tagged enums"
+            ),
+            Self::OtherNotUnit(_) => write!(f, "#[serde(other)] must be on a unit variant"),
+            Self::OtherNotLast(_) => write!(f, "#[serde(other)] must be on the last variant"),
+            Self::CatchAllNotLast(_) => write!(
+                f,
+                "the newtype variant of a #[serde(field_identifier)] must be the last variant"
+            ),
+            Self::NotUnitVariant(_) => write!(
+                f,
+                "#[serde(field_identifier)] and #[serde(variant_identifier)] may only contain unit variants"
+            ),
+        }
+    }
+}
+impl error::Error for CheckIdentifierError {}
+impl From<CheckIdentifierError> for Error {
+    fn from(err: CheckIdentifierError) -> Self {
+        match err {
+            CheckIdentifierError::FromAttrsFailed(err) => Self::from(err),
+            CheckIdentifierError::NotAnEnum(span)
+            | CheckIdentifierError::DuplicateOther(span)
+            | CheckIdentifierError::OtherInVariantIdentifier(span)
+            | CheckIdentifierError::OtherInUntagged(span)
+            | CheckIdentifierError::OtherInExternallyTagged(span)
+            | CheckIdentifierError::OtherNotUnit(span)
+            | CheckIdentifierError::OtherNotLast(span)
+            | CheckIdentifierError::CatchAllNotLast(span)
+            | CheckIdentifierError::NotUnitVariant(span) => Self::new(span, err),
+        }
+    }
+}
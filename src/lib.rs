@@ -1,5 +1,7 @@
 //! Serde Attributes. [Extract from](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L290)
 //!
+//! `with-syn2` and `with-darling020` only cover `Alias`, `Rename`, `RenameAll` and
+//! `RenameAllFields`; every other attribute needs `with-syn` or `with-darling`.
 
 #[cfg(feature = "attr-alias")]
 pub mod alias;
@@ -16,11 +18,127 @@ pub mod rename_all;
 #[cfg(feature = "attr-rename-all")]
 pub use rename_all::{RenameAll, RenameAllIndependent};
 
-/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L26)
-#[cfg(any(feature = "attr-rename", feature = "attr-rename-all"))]
+#[cfg(feature = "attr-rename-all-fields")]
+pub mod rename_all_fields;
+#[cfg(feature = "attr-rename-all-fields")]
+pub use rename_all_fields::{RenameAllFields, RenameAllFieldsIndependent};
+
+#[cfg(feature = "attr-enum-repr")]
+pub mod enum_repr;
+#[cfg(feature = "attr-enum-repr")]
+pub use enum_repr::{EnumRepr, Untagged};
+
+// Not re-exported at the root, where it would shadow the prelude's `Default`.
+#[cfg(feature = "attr-default")]
+pub mod default;
+
+#[cfg(feature = "attr-skip")]
+pub mod skip;
+#[cfg(feature = "attr-skip")]
+pub use skip::Skip;
+
+#[cfg(feature = "attr-with")]
+pub mod with;
+#[cfg(feature = "attr-with")]
+pub use with::{With, WithIndependent};
+
+#[cfg(feature = "attr-flatten")]
+pub mod flatten;
+#[cfg(feature = "attr-flatten")]
+pub use flatten::Flatten;
+
+#[cfg(feature = "attr-deny-unknown-fields")]
+pub mod deny_unknown_fields;
+#[cfg(feature = "attr-deny-unknown-fields")]
+pub use deny_unknown_fields::DenyUnknownFields;
+
+#[cfg(feature = "attr-transparent")]
+pub mod transparent;
+#[cfg(feature = "attr-transparent")]
+pub use transparent::Transparent;
+
+#[cfg(feature = "attr-conversion")]
+pub mod conversion;
+#[cfg(feature = "attr-conversion")]
+pub use conversion::{Conversion, ConversionFrom};
+
+#[cfg(feature = "attr-bound")]
+pub mod bound;
+#[cfg(feature = "attr-bound")]
+pub use bound::{Bound, BoundIndependent};
+
+#[cfg(feature = "attr-crate-path")]
+pub mod crate_path;
+#[cfg(feature = "attr-crate-path")]
+pub use crate_path::CratePath;
+
+#[cfg(feature = "attr-remote")]
+pub mod remote;
+#[cfg(feature = "attr-remote")]
+pub use remote::Remote;
+
+#[cfg(feature = "attr-borrow")]
+pub mod borrow;
+#[cfg(feature = "attr-borrow")]
+pub use borrow::Borrow;
+
+#[cfg(feature = "attr-getter")]
+pub mod getter;
+#[cfg(feature = "attr-getter")]
+pub use getter::Getter;
+
+#[cfg(feature = "attr-identifier")]
+pub mod identifier;
+#[cfg(feature = "attr-identifier")]
+pub use identifier::{Identifier, Other};
+
+#[cfg(feature = "attr-expecting")]
+pub mod expecting;
+#[cfg(feature = "attr-expecting")]
+pub use expecting::Expecting;
+
 #[cfg(feature = "with-syn")]
-pub const SERIALIZE: &str = "serialize";
-#[cfg(any(feature = "attr-rename", feature = "attr-rename-all"))]
+pub mod attrs;
+
+#[cfg(any(feature = "with-syn", feature = "with-syn2"))]
+pub mod error;
+#[cfg(any(feature = "with-syn", feature = "with-syn2"))]
+pub use error::Error;
+
+#[cfg(feature = "with-syn2")]
+pub mod syn2_attrs;
+
+#[cfg(feature = "with-syn")]
+pub mod container;
+#[cfg(feature = "with-syn")]
+pub use container::Container;
+
 #[cfg(feature = "with-syn")]
+pub mod variant;
+#[cfg(feature = "with-syn")]
+pub use variant::Variant;
+
+#[cfg(feature = "with-syn")]
+pub mod field;
+#[cfg(feature = "with-syn")]
+pub use field::Field;
+
+#[cfg(all(
+    feature = "attr-alias",
+    feature = "attr-rename-all-fields",
+    feature = "with-syn"
+))]
+pub mod name;
+#[cfg(all(
+    feature = "attr-alias",
+    feature = "attr-rename-all-fields",
+    feature = "with-syn"
+))]
+pub use name::Name;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L26)
+#[cfg(any(feature = "with-syn", feature = "with-syn2"))]
+pub const SERIALIZE: &str = "serialize";
+#[cfg(any(feature = "with-syn", feature = "with-syn2"))]
 /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L14)
 pub const DESERIALIZE: &str = "deserialize";
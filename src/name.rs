@@ -0,0 +1,168 @@
+//! Effective serialized and deserialized names, combining `rename`, `rename_all` and `alias`.
+//!
+//! [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L136-L210)
+
+use std::collections::BTreeSet;
+
+use serde_rename_rule::RenameRule;
+use syn::{Ident, Member};
+
+use crate::{Alias, Container, Field, Rename, RenameAll, Variant};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Name {
+    pub serialize: String,
+    pub deserialize: String,
+    /// Every name accepted when deserializing, `deserialize` included.
+    pub deserialize_aliases: Vec<String>,
+}
+
+impl Container {
+    /// Container names ignore `rename_all` and `alias`.
+    pub fn name(&self, ident: &Ident) -> Name {
+        NameBuilder::new(unraw(ident))
+            .rename(self.rename.as_ref())
+            .build(false)
+    }
+}
+
+impl Variant {
+    /// Applies the variant's `rename`, else the container's `rename_all`, then its `alias`es.
+    pub fn name(&self, ident: &Ident, container: &Container) -> Name {
+        NameBuilder::new(unraw(ident))
+            .rename_all(container.rename_all.as_ref(), RenameRule::apply_to_variant)
+            .rename(self.rename.as_ref())
+            .alias(&self.alias)
+            .build(true)
+    }
+}
+
+impl Field {
+    /// Name of a struct field, renamed by the container's `rename_all`.
+    pub fn name(&self, member: &Member, container: &Container) -> Name {
+        NameBuilder::new(member_name(member))
+            .rename_all(container.rename_all.as_ref(), RenameRule::apply_to_field)
+            .rename(self.rename.as_ref())
+            .alias(&self.alias)
+            .build(true)
+    }
+
+    /// Name of a struct variant field, renamed by the variant's `rename_all`, else by the
+    /// container's `rename_all_fields`.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/ast.rs#L86-L97)
+    pub fn name_in_variant(
+        &self,
+        member: &Member,
+        variant: &Variant,
+        container: &Container,
+    ) -> Name {
+        NameBuilder::new(member_name(member))
+            .rename_rules(
+                variant
+                    .rename_all
+                    .as_ref()
+                    .and_then(RenameAll::ser_rule)
+                    .or_else(|| container.rename_all_fields.as_ref()?.ser_rule()),
+                variant
+                    .rename_all
+                    .as_ref()
+                    .and_then(RenameAll::de_rule)
+                    .or_else(|| container.rename_all_fields.as_ref()?.de_rule()),
+                RenameRule::apply_to_field,
+            )
+            .rename(self.rename.as_ref())
+            .alias(&self.alias)
+            .build(true)
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L144-L150)
+fn unraw(ident: &Ident) -> String {
+    ident.to_string().trim_start_matches("r#").to_owned()
+}
+
+fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => unraw(ident),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+struct NameBuilder {
+    serialize: String,
+    deserialize: String,
+    aliases: BTreeSet<String>,
+}
+
+impl NameBuilder {
+    fn new(source_name: String) -> Self {
+        Self {
+            serialize: source_name.clone(),
+            deserialize: source_name,
+            aliases: BTreeSet::new(),
+        }
+    }
+
+    /// Must come before `rename`, which takes precedence per side.
+    fn rename_all(
+        self,
+        rename_all: Option<&RenameAll>,
+        apply: fn(&RenameRule, &str) -> String,
+    ) -> Self {
+        self.rename_rules(
+            rename_all.and_then(RenameAll::ser_rule),
+            rename_all.and_then(RenameAll::de_rule),
+            apply,
+        )
+    }
+
+    fn rename_rules(
+        mut self,
+        ser_rule: Option<&RenameRule>,
+        de_rule: Option<&RenameRule>,
+        apply: fn(&RenameRule, &str) -> String,
+    ) -> Self {
+        if let Some(rule) = ser_rule {
+            self.serialize = apply(rule, &self.serialize);
+        }
+        if let Some(rule) = de_rule {
+            self.deserialize = apply(rule, &self.deserialize);
+        }
+        self
+    }
+
+    fn rename(mut self, rename: Option<&Rename>) -> Self {
+        if let Some(name) = rename.and_then(Rename::ser_name) {
+            self.serialize = name.to_owned();
+        }
+        if let Some(name) = rename.and_then(Rename::de_name) {
+            self.deserialize = name.to_owned();
+            self.aliases.insert(name.to_owned());
+        }
+        self
+    }
+
+    fn alias(mut self, alias: &[Alias]) -> Self {
+        self.aliases
+            .extend(alias.iter().map(|Alias(name)| name.to_owned()));
+        self
+    }
+
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L201-L208)
+    fn build(self, with_aliases: bool) -> Name {
+        let mut deserialize_aliases: Vec<_> = if with_aliases {
+            self.aliases.into_iter().collect()
+        } else {
+            vec![]
+        };
+        if !deserialize_aliases.contains(&self.deserialize) {
+            deserialize_aliases.push(self.deserialize.clone());
+        }
+        Name {
+            serialize: self.serialize,
+            deserialize: self.deserialize,
+            deserialize_aliases,
+        }
+    }
+}
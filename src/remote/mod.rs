@@ -0,0 +1,17 @@
+//! [Official doc](https://serde.rs/container-attrs.html#remote)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use ::syn::Path;
+
+/// `#[serde(remote = "...")]`, the type the derive is mirroring.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Remote(pub Path);
+impl Remote {
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
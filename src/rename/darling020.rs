@@ -0,0 +1,33 @@
+use std::convert::TryFrom;
+
+use darling_core020::{Error as DarlingError, FromMeta};
+use syn2::{Expr, ExprLit, Meta};
+
+use super::{syn2::FromMetaError, Rename};
+
+impl FromMeta for Rename {
+    fn from_meta(meta: &Meta) -> Result<Self, DarlingError> {
+        Self::try_from(meta).map_err(|err| match err {
+            FromMetaError::MetaTypeOrPathMismatch(meta) => match meta {
+                Meta::Path(_) => DarlingError::unexpected_type("Meta::Path"),
+                Meta::List(meta_list) => DarlingError::unknown_field_path(&meta_list.path),
+                Meta::NameValue(meta_name_value) => {
+                    DarlingError::unknown_field_path(&meta_name_value.path)
+                }
+            },
+            FromMetaError::LitTypeMismatch(expr) => match *expr {
+                Expr::Lit(ExprLit { lit, .. }) => DarlingError::unexpected_lit_type(&lit),
+                expr => DarlingError::unexpected_expr_type(&expr),
+            },
+            FromMetaError::NestedMetaTypeMismatch(_, _) => {
+                DarlingError::unexpected_type("NestedMeta::Meta(!Meta::NameValue)")
+            }
+            FromMetaError::NestedMetaPathMismatch(_, path) => {
+                DarlingError::unknown_field_path(&path)
+            }
+            FromMetaError::AtLeastOneOfSerAndDe => {
+                DarlingError::custom("must be at least one the serialize and deserialize")
+            }
+        })
+    }
+}
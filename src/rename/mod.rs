@@ -2,8 +2,14 @@
 
 #[cfg(feature = "with-darling")]
 pub mod darling;
+#[cfg(feature = "with-darling020")]
+pub mod darling020;
+#[cfg(feature = "with-quote")]
+pub mod quote;
 #[cfg(feature = "with-syn")]
 pub mod syn;
+#[cfg(feature = "with-syn2")]
+pub mod syn2;
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub enum Rename {
@@ -43,6 +49,27 @@ impl Rename {
             _ => None,
         }
     }
+
+    /// Combines two `rename` attributes of the same item, e.g.
+    /// `#[serde(rename(serialize = "a"))] #[serde(rename(deserialize = "b"))]`.
+    ///
+    /// Returns `None` if both set the same side.
+    pub fn merge(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (
+                Self::Independent(RenameIndependent::Serialize(serialize)),
+                Self::Independent(RenameIndependent::Deserialize(deserialize)),
+            )
+            | (
+                Self::Independent(RenameIndependent::Deserialize(deserialize)),
+                Self::Independent(RenameIndependent::Serialize(serialize)),
+            ) => Some(Self::Independent(RenameIndependent::Both {
+                serialize,
+                deserialize,
+            })),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -89,4 +116,29 @@ mod tests {
             Some("de")
         );
     }
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(
+            Rename::Independent(RenameIndependent::Serialize("ser".to_owned())).merge(
+                Rename::Independent(RenameIndependent::Deserialize("de".to_owned()))
+            ),
+            Some(Rename::Independent(RenameIndependent::Both {
+                serialize: "ser".to_owned(),
+                deserialize: "de".to_owned(),
+            }))
+        );
+        assert_eq!(
+            Rename::Normal("foo".to_owned()).merge(Rename::Independent(
+                RenameIndependent::Deserialize("de".to_owned())
+            )),
+            None
+        );
+        assert_eq!(
+            Rename::Independent(RenameIndependent::Serialize("a".to_owned())).merge(
+                Rename::Independent(RenameIndependent::Serialize("b".to_owned()))
+            ),
+            None
+        );
+    }
 }
@@ -0,0 +1,29 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use super::{Rename, RenameIndependent};
+
+/// Emits `rename = "..."` or `rename(serialize = "...", deserialize = "...")`, the meta
+/// `TryFrom<&Meta>` parses.
+impl ToTokens for Rename {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Normal(name) => quote!(rename = #name),
+            Self::Independent(independent) => quote!(rename(#independent)),
+        })
+    }
+}
+
+/// Emits the nested `serialize = "...", deserialize = "..."` of `rename(...)`.
+impl ToTokens for RenameIndependent {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Serialize(serialize) => quote!(serialize = #serialize),
+            Self::Deserialize(deserialize) => quote!(deserialize = #deserialize),
+            Self::Both {
+                serialize,
+                deserialize,
+            } => quote!(serialize = #serialize, deserialize = #deserialize),
+        })
+    }
+}
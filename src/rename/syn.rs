@@ -1,8 +1,12 @@
 use std::{convert::TryFrom, error, fmt};
 
-use syn::{Lit, Meta, MetaNameValue, NestedMeta};
+use proc_macro2::Span;
+use syn::{punctuated::Punctuated, Ident, Lit, Meta, MetaList, MetaNameValue, NestedMeta};
 
-use crate::{DESERIALIZE, SERIALIZE};
+use crate::{
+    attrs::{meta_name, meta_span, name_value_meta, nested_meta_span},
+    Error, DESERIALIZE, SERIALIZE,
+};
 
 use super::{Rename, RenameIndependent};
 
@@ -75,6 +79,31 @@ impl Rename {
             meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
         }
     }
+
+    /// Builds `path_name = "..."` or `path_name(serialize = "...", deserialize = "...")`, the
+    /// inverse of [`try_from_meta`](Self::try_from_meta).
+    pub fn to_meta(&self, path_name: &str) -> Meta {
+        let independent = match self {
+            Self::Normal(name) => return name_value_meta(path_name, name),
+            Self::Independent(independent) => independent,
+        };
+        let nested = match independent {
+            RenameIndependent::Serialize(ser_name) => vec![(SERIALIZE, ser_name)],
+            RenameIndependent::Deserialize(de_name) => vec![(DESERIALIZE, de_name)],
+            RenameIndependent::Both {
+                serialize: ser_name,
+                deserialize: de_name,
+            } => vec![(SERIALIZE, ser_name), (DESERIALIZE, de_name)],
+        };
+        Meta::List(MetaList {
+            path: Ident::new(path_name, Span::call_site()).into(),
+            paren_token: Default::default(),
+            nested: nested
+                .into_iter()
+                .map(|(name, value)| NestedMeta::Meta(name_value_meta(name, value)))
+                .collect::<Punctuated<_, _>>(),
+        })
+    }
 }
 
 /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L319-L333)
@@ -86,6 +115,13 @@ impl<'a> TryFrom<&'a Meta> for Rename {
     }
 }
 
+/// Builds `rename = "..."` or `rename(serialize = "...", deserialize = "...")`.
+impl From<&Rename> for Meta {
+    fn from(rename: &Rename) -> Self {
+        rename.to_meta(RENAME)
+    }
+}
+
 pub enum FromMetaError<'a> {
     MetaTypeOrPathMismatch(&'a Meta),
     LitTypeMismatch(&'a Lit),
@@ -93,6 +129,46 @@ pub enum FromMetaError<'a> {
     NestedMetaPathMismatch(&'a NestedMeta, &'a MetaNameValue),
     AtLeastOneOfSerAndDe,
 }
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1500-L1549)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        let name = meta_name(meta);
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(
+                    r#"malformed {0} attribute, expected `{0} = "..."` or `{0}(serialize = "...", deserialize = "...")`"#,
+                    name
+                ),
+            ),
+            Self::LitTypeMismatch(lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    name
+                ),
+            ),
+            Self::NestedMetaTypeMismatch(nested_meta) | Self::NestedMetaPathMismatch(nested_meta, _) => {
+                Error::new(
+                    nested_meta_span(nested_meta),
+                    format_args!(
+                        "malformed {0} attribute, expected `{0}(serialize = ..., deserialize = ...)`",
+                        name
+                    ),
+                )
+            }
+            Self::AtLeastOneOfSerAndDe => Error::new(
+                meta_span(meta),
+                format_args!(
+                    "malformed {0} attribute, expected at least one of `serialize` and `deserialize`",
+                    name
+                ),
+            ),
+        }
+    }
+}
 impl<'a> fmt::Debug for FromMetaError<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
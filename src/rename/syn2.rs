@@ -0,0 +1,127 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn2::{Expr, Meta, MetaList, Path, Token};
+
+use crate::{syn2_attrs::expr_lit_str, DESERIALIZE, SERIALIZE};
+
+use super::{Rename, RenameIndependent};
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/symbol.rs#L25)
+pub const RENAME: &str = "rename";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L1343-L1395)
+pub fn try_from_meta<'a>(meta: &'a Meta, path_name: &str) -> Result<Rename, FromMetaError<'a>> {
+    match meta {
+        Meta::NameValue(ref meta_name_value) if meta_name_value.path.is_ident(path_name) => {
+            match expr_lit_str(&meta_name_value.value) {
+                Some(s) => Ok(Rename::Normal(s.value())),
+                None => Err(FromMetaError::LitTypeMismatch(Box::new(
+                    meta_name_value.value.clone(),
+                ))),
+            }
+        }
+        Meta::List(ref meta_list) if meta_list.path.is_ident(path_name) => {
+            let mut ser_name = None;
+            let mut de_name = None;
+            let mut nested_err = None;
+
+            // `parse_nested_meta` only lets syn errors out, so the structured one is kept aside.
+            meta_list
+                .parse_nested_meta(|nested_meta| {
+                    let malformed = || {
+                        nested_meta.error(format_args!(
+                            "malformed {0} attribute, expected `{0}(serialize = ..., deserialize = ...)`",
+                            path_name,
+                        ))
+                    };
+                    if !nested_meta.input.peek(Token![=]) {
+                        return Err(malformed());
+                    }
+                    let name = if nested_meta.path.is_ident(SERIALIZE) {
+                        &mut ser_name
+                    } else if nested_meta.path.is_ident(DESERIALIZE) {
+                        &mut de_name
+                    } else {
+                        let err = malformed();
+                        nested_err = Some(FromMetaError::NestedMetaPathMismatch(
+                            meta_list,
+                            nested_meta.path,
+                        ));
+                        return Err(err);
+                    };
+
+                    let expr: Expr = nested_meta.value()?.parse()?;
+                    match expr_lit_str(&expr) {
+                        Some(s) => *name = Some(s.value()),
+                        None => {
+                            let err = nested_meta.error(format_args!(
+                                "expected {} attribute to be a string",
+                                path_name
+                            ));
+                            nested_err = Some(FromMetaError::LitTypeMismatch(Box::new(expr)));
+                            return Err(err);
+                        }
+                    }
+                    Ok(())
+                })
+                .map_err(|err| {
+                    nested_err
+                        .take()
+                        .unwrap_or(FromMetaError::NestedMetaTypeMismatch(meta_list, err))
+                })?;
+
+            match (ser_name, de_name) {
+                (None, None) => Err(FromMetaError::AtLeastOneOfSerAndDe),
+                (None, Some(de_name)) => {
+                    Ok(Rename::Independent(RenameIndependent::Deserialize(de_name)))
+                }
+                (Some(ser_name), None) => {
+                    Ok(Rename::Independent(RenameIndependent::Serialize(ser_name)))
+                }
+                (Some(ser_name), Some(de_name)) => {
+                    Ok(Rename::Independent(RenameIndependent::Both {
+                        serialize: ser_name,
+                        deserialize: de_name,
+                    }))
+                }
+            }
+        }
+        meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L276-L281)
+impl<'a> TryFrom<&'a Meta> for Rename {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        try_from_meta(meta, RENAME)
+    }
+}
+
+/// Nested items are parsed out of the list's tokens, so their parts are owned.
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(Box<Expr>),
+    /// A nested item that is not `name = value`, e.g. `rename(serialize)`.
+    NestedMetaTypeMismatch(&'a MetaList, syn2::Error),
+    NestedMetaPathMismatch(&'a MetaList, Path),
+    AtLeastOneOfSerAndDe,
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+            Self::NestedMetaTypeMismatch(_, err) => write!(f, "NestedMetaTypeMismatch({})", err),
+            Self::NestedMetaPathMismatch(_, _) => write!(f, "NestedMetaPathMismatch"),
+            Self::AtLeastOneOfSerAndDe => write!(f, "AtLeastOneOfSerAndDe"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
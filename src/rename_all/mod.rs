@@ -6,8 +6,14 @@ use serde_rename_rule::RenameRule;
 
 #[cfg(feature = "with-darling")]
 pub mod darling;
+#[cfg(feature = "with-darling020")]
+pub mod darling020;
+#[cfg(feature = "with-quote")]
+pub mod quote;
 #[cfg(feature = "with-syn")]
 pub mod syn;
+#[cfg(feature = "with-syn2")]
+pub mod syn2;
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
 pub enum RenameAll {
@@ -47,6 +53,27 @@ impl RenameAll {
             _ => None,
         }
     }
+
+    /// Combines two `rename_all` attributes of the same item, e.g.
+    /// `#[serde(rename_all(serialize = "a"))] #[serde(rename_all(deserialize = "b"))]`.
+    ///
+    /// Returns `None` if both set the same side.
+    pub fn merge(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (
+                Self::Independent(RenameAllIndependent::Serialize(serialize)),
+                Self::Independent(RenameAllIndependent::Deserialize(deserialize)),
+            )
+            | (
+                Self::Independent(RenameAllIndependent::Deserialize(deserialize)),
+                Self::Independent(RenameAllIndependent::Serialize(serialize)),
+            ) => Some(Self::Independent(RenameAllIndependent::Both {
+                serialize,
+                deserialize,
+            })),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -103,4 +130,22 @@ mod tests {
             Some(&RenameRule::UpperCase)
         );
     }
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(
+            RenameAll::Independent(RenameAllIndependent::Deserialize(RenameRule::UpperCase)).merge(
+                RenameAll::Independent(RenameAllIndependent::Serialize(RenameRule::LowerCase))
+            ),
+            Some(RenameAll::Independent(RenameAllIndependent::Both {
+                serialize: RenameRule::LowerCase,
+                deserialize: RenameRule::UpperCase,
+            }))
+        );
+        assert_eq!(
+            RenameAll::Normal(RenameRule::SnakeCase)
+                .merge(RenameAll::Normal(RenameRule::SnakeCase)),
+            None
+        );
+    }
 }
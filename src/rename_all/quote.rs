@@ -0,0 +1,42 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use super::{RenameAll, RenameAllIndependent};
+
+/// Emits `rename_all = "..."` or `rename_all(serialize = "...", deserialize = "...")`, the meta
+/// `TryFrom<&Meta>` parses.
+impl ToTokens for RenameAll {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Normal(rule) => {
+                let rule = rule.to_rename_all_str();
+                quote!(rename_all = #rule)
+            }
+            Self::Independent(independent) => quote!(rename_all(#independent)),
+        })
+    }
+}
+
+/// Emits the nested `serialize = "...", deserialize = "..."` of `rename_all(...)`.
+impl ToTokens for RenameAllIndependent {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Serialize(serialize) => {
+                let serialize = serialize.to_rename_all_str();
+                quote!(serialize = #serialize)
+            }
+            Self::Deserialize(deserialize) => {
+                let deserialize = deserialize.to_rename_all_str();
+                quote!(deserialize = #deserialize)
+            }
+            Self::Both {
+                serialize,
+                deserialize,
+            } => {
+                let serialize = serialize.to_rename_all_str();
+                let deserialize = deserialize.to_rename_all_str();
+                quote!(serialize = #serialize, deserialize = #deserialize)
+            }
+        })
+    }
+}
@@ -3,19 +3,21 @@ use std::{convert::TryFrom, error, fmt};
 use serde_rename_rule::{ParseError as RenameRuleParseError, RenameRule};
 use syn::{Lit, Meta, MetaNameValue, NestedMeta};
 
-use crate::rename::{syn::FromMetaError as RenameFromMetaError, Rename, RenameIndependent};
+use crate::{
+    attrs::meta_span,
+    rename::{syn::FromMetaError as RenameFromMetaError, Rename, RenameIndependent},
+    Error,
+};
 
 use super::{RenameAll, RenameAllIndependent};
 
 /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L24)
 pub const RENAME_ALL: &str = "rename_all";
 
-/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L335-L364)
-impl<'a> TryFrom<&'a Meta> for RenameAll {
-    type Error = FromMetaError<'a>;
-
-    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
-        match Rename::try_from_meta(meta, RENAME_ALL) {
+impl RenameAll {
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L335-L364)
+    pub fn try_from_meta<'a>(meta: &'a Meta, path_name: &str) -> Result<Self, FromMetaError<'a>> {
+        match Rename::try_from_meta(meta, path_name) {
             Ok(rename) => match rename {
                 Rename::Normal(name) => RenameRule::from_rename_all_str(name.as_str())
                     .map(Self::Normal)
@@ -59,6 +61,44 @@ impl<'a> TryFrom<&'a Meta> for RenameAll {
             },
         }
     }
+
+    /// Builds `path_name = "..."` or `path_name(serialize = "...", deserialize = "...")`, the
+    /// inverse of [`try_from_meta`](Self::try_from_meta).
+    pub fn to_meta(&self, path_name: &str) -> Meta {
+        let rename = match self {
+            Self::Normal(rule) => Rename::Normal(rule.to_rename_all_str().to_owned()),
+            Self::Independent(RenameAllIndependent::Serialize(ser_rule)) => Rename::Independent(
+                RenameIndependent::Serialize(ser_rule.to_rename_all_str().to_owned()),
+            ),
+            Self::Independent(RenameAllIndependent::Deserialize(de_rule)) => Rename::Independent(
+                RenameIndependent::Deserialize(de_rule.to_rename_all_str().to_owned()),
+            ),
+            Self::Independent(RenameAllIndependent::Both {
+                serialize: ser_rule,
+                deserialize: de_rule,
+            }) => Rename::Independent(RenameIndependent::Both {
+                serialize: ser_rule.to_rename_all_str().to_owned(),
+                deserialize: de_rule.to_rename_all_str().to_owned(),
+            }),
+        };
+        rename.to_meta(path_name)
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L335-L364)
+impl<'a> TryFrom<&'a Meta> for RenameAll {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        Self::try_from_meta(meta, RENAME_ALL)
+    }
+}
+
+/// Builds `rename_all = "..."` or `rename_all(serialize = "...", deserialize = "...")`.
+impl From<&RenameAll> for Meta {
+    fn from(rename_all: &RenameAll) -> Self {
+        rename_all.to_meta(RENAME_ALL)
+    }
 }
 
 pub enum FromMetaError<'a> {
@@ -69,6 +109,32 @@ pub enum FromMetaError<'a> {
     AtLeastOneOfSerAndDe,
     RenameRuleParseError(RenameRuleParseError),
 }
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L335-L364)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        let err = match self {
+            Self::MetaTypeOrPathMismatch(meta) => RenameFromMetaError::MetaTypeOrPathMismatch(meta),
+            Self::LitTypeMismatch(lit) => RenameFromMetaError::LitTypeMismatch(lit),
+            Self::NestedMetaTypeMismatch(nested_meta) => {
+                RenameFromMetaError::NestedMetaTypeMismatch(nested_meta)
+            }
+            Self::NestedMetaPathMismatch(nested_meta, meta_name_value) => {
+                RenameFromMetaError::NestedMetaPathMismatch(nested_meta, meta_name_value)
+            }
+            Self::AtLeastOneOfSerAndDe => RenameFromMetaError::AtLeastOneOfSerAndDe,
+            Self::RenameRuleParseError(err) => {
+                let span = match meta {
+                    Meta::NameValue(meta_name_value) => meta_name_value.lit.span(),
+                    meta => meta_span(meta),
+                };
+                return Error::new(span, err.msg_for_rename_all());
+            }
+        };
+        err.into_error(meta)
+    }
+}
 impl<'a> fmt::Debug for FromMetaError<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
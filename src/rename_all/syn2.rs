@@ -0,0 +1,93 @@
+use std::{convert::TryFrom, error, fmt};
+
+use serde_rename_rule::{ParseError as RenameRuleParseError, RenameRule};
+use syn2::{Expr, Meta, MetaList, Path};
+
+use crate::rename::{
+    syn2::{try_from_meta as rename_try_from_meta, FromMetaError as RenameFromMetaError},
+    Rename, RenameIndependent,
+};
+
+use super::{RenameAll, RenameAllIndependent};
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/symbol.rs#L26)
+pub const RENAME_ALL: &str = "rename_all";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L282-L302)
+pub fn try_from_meta<'a>(meta: &'a Meta, path_name: &str) -> Result<RenameAll, FromMetaError<'a>> {
+    match rename_try_from_meta(meta, path_name) {
+        Ok(rename) => match rename {
+            Rename::Normal(name) => RenameRule::from_rename_all_str(name.as_str())
+                .map(RenameAll::Normal)
+                .map_err(FromMetaError::RenameRuleParseError),
+            Rename::Independent(RenameIndependent::Serialize(ser_name)) => {
+                RenameRule::from_rename_all_str(ser_name.as_str())
+                    .map(|x| RenameAll::Independent(RenameAllIndependent::Serialize(x)))
+                    .map_err(FromMetaError::RenameRuleParseError)
+            }
+            Rename::Independent(RenameIndependent::Deserialize(de_name)) => {
+                RenameRule::from_rename_all_str(de_name.as_str())
+                    .map(|x| RenameAll::Independent(RenameAllIndependent::Deserialize(x)))
+                    .map_err(FromMetaError::RenameRuleParseError)
+            }
+            Rename::Independent(RenameIndependent::Both {
+                serialize: ser_name,
+                deserialize: de_name,
+            }) => Ok(RenameAll::Independent(RenameAllIndependent::Both {
+                serialize: RenameRule::from_rename_all_str(ser_name.as_str())
+                    .map_err(FromMetaError::RenameRuleParseError)?,
+                deserialize: RenameRule::from_rename_all_str(de_name.as_str())
+                    .map_err(FromMetaError::RenameRuleParseError)?,
+            })),
+        },
+        Err(err) => match err {
+            RenameFromMetaError::MetaTypeOrPathMismatch(meta) => {
+                Err(FromMetaError::MetaTypeOrPathMismatch(meta))
+            }
+            RenameFromMetaError::LitTypeMismatch(expr) => Err(FromMetaError::LitTypeMismatch(expr)),
+            RenameFromMetaError::NestedMetaTypeMismatch(meta_list, err) => {
+                Err(FromMetaError::NestedMetaTypeMismatch(meta_list, err))
+            }
+            RenameFromMetaError::NestedMetaPathMismatch(meta_list, path) => {
+                Err(FromMetaError::NestedMetaPathMismatch(meta_list, path))
+            }
+            RenameFromMetaError::AtLeastOneOfSerAndDe => Err(FromMetaError::AtLeastOneOfSerAndDe),
+        },
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L282-L302)
+impl<'a> TryFrom<&'a Meta> for RenameAll {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        try_from_meta(meta, RENAME_ALL)
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(Box<Expr>),
+    NestedMetaTypeMismatch(&'a MetaList, syn2::Error),
+    NestedMetaPathMismatch(&'a MetaList, Path),
+    AtLeastOneOfSerAndDe,
+    RenameRuleParseError(RenameRuleParseError),
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+            Self::NestedMetaTypeMismatch(_, err) => write!(f, "NestedMetaTypeMismatch({})", err),
+            Self::NestedMetaPathMismatch(_, _) => write!(f, "NestedMetaPathMismatch"),
+            Self::AtLeastOneOfSerAndDe => write!(f, "AtLeastOneOfSerAndDe"),
+            Self::RenameRuleParseError(_) => write!(f, "RenameRuleParseError"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
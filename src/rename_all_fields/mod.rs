@@ -0,0 +1,171 @@
+//! [Official doc](https://serde.rs/container-attrs.html#rename_all_fields)
+
+use serde_rename_rule::RenameRule;
+
+use crate::{RenameAll, RenameAllIndependent};
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-darling020")]
+pub mod darling020;
+#[cfg(feature = "with-quote")]
+pub mod quote;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+#[cfg(feature = "with-syn2")]
+pub mod syn2;
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum RenameAllFields {
+    Normal(RenameRule),
+    Independent(RenameAllFieldsIndependent),
+}
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum RenameAllFieldsIndependent {
+    Serialize(RenameRule),
+    Deserialize(RenameRule),
+    Both {
+        serialize: RenameRule,
+        deserialize: RenameRule,
+    },
+}
+impl RenameAllFields {
+    pub fn ser_rule(&self) -> Option<&RenameRule> {
+        match self {
+            Self::Normal(rule)
+            | Self::Independent(RenameAllFieldsIndependent::Serialize(rule))
+            | Self::Independent(RenameAllFieldsIndependent::Both {
+                serialize: rule,
+                deserialize: _,
+            }) => Some(rule),
+            _ => None,
+        }
+    }
+
+    pub fn de_rule(&self) -> Option<&RenameRule> {
+        match self {
+            Self::Normal(rule)
+            | Self::Independent(RenameAllFieldsIndependent::Deserialize(rule))
+            | Self::Independent(RenameAllFieldsIndependent::Both {
+                serialize: _,
+                deserialize: rule,
+            }) => Some(rule),
+            _ => None,
+        }
+    }
+
+    /// Combines two `rename_all_fields` attributes of the same enum.
+    ///
+    /// Returns `None` if both set the same side.
+    pub fn merge(self, other: Self) -> Option<Self> {
+        RenameAll::from(self)
+            .merge(RenameAll::from(other))
+            .map(Self::from)
+    }
+}
+
+impl From<RenameAll> for RenameAllFields {
+    fn from(rename_all: RenameAll) -> Self {
+        match rename_all {
+            RenameAll::Normal(rule) => Self::Normal(rule),
+            RenameAll::Independent(independent) => Self::Independent(independent.into()),
+        }
+    }
+}
+impl From<RenameAllFields> for RenameAll {
+    fn from(rename_all_fields: RenameAllFields) -> Self {
+        match rename_all_fields {
+            RenameAllFields::Normal(rule) => Self::Normal(rule),
+            RenameAllFields::Independent(independent) => Self::Independent(independent.into()),
+        }
+    }
+}
+impl From<RenameAllIndependent> for RenameAllFieldsIndependent {
+    fn from(independent: RenameAllIndependent) -> Self {
+        match independent {
+            RenameAllIndependent::Serialize(rule) => Self::Serialize(rule),
+            RenameAllIndependent::Deserialize(rule) => Self::Deserialize(rule),
+            RenameAllIndependent::Both {
+                serialize,
+                deserialize,
+            } => Self::Both {
+                serialize,
+                deserialize,
+            },
+        }
+    }
+}
+impl From<RenameAllFieldsIndependent> for RenameAllIndependent {
+    fn from(independent: RenameAllFieldsIndependent) -> Self {
+        match independent {
+            RenameAllFieldsIndependent::Serialize(rule) => Self::Serialize(rule),
+            RenameAllFieldsIndependent::Deserialize(rule) => Self::Deserialize(rule),
+            RenameAllFieldsIndependent::Both {
+                serialize,
+                deserialize,
+            } => Self::Both {
+                serialize,
+                deserialize,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ser_rule_and_de_rule() {
+        assert_eq!(
+            RenameAllFields::Normal(RenameRule::SnakeCase).ser_rule(),
+            Some(&RenameRule::SnakeCase)
+        );
+        assert_eq!(
+            RenameAllFields::Normal(RenameRule::SnakeCase).de_rule(),
+            Some(&RenameRule::SnakeCase)
+        );
+
+        assert_eq!(
+            RenameAllFields::Independent(RenameAllFieldsIndependent::Serialize(
+                RenameRule::LowerCase
+            ))
+            .de_rule(),
+            None
+        );
+        assert_eq!(
+            RenameAllFields::Independent(RenameAllFieldsIndependent::Deserialize(
+                RenameRule::UpperCase
+            ))
+            .ser_rule(),
+            None
+        );
+
+        assert_eq!(
+            RenameAllFields::Independent(RenameAllFieldsIndependent::Both {
+                serialize: RenameRule::LowerCase,
+                deserialize: RenameRule::UpperCase,
+            })
+            .de_rule(),
+            Some(&RenameRule::UpperCase)
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(
+            RenameAllFields::Independent(RenameAllFieldsIndependent::Serialize(
+                RenameRule::LowerCase
+            ))
+            .merge(RenameAllFields::Independent(
+                RenameAllFieldsIndependent::Deserialize(RenameRule::UpperCase)
+            )),
+            Some(RenameAllFields::Independent(
+                RenameAllFieldsIndependent::Both {
+                    serialize: RenameRule::LowerCase,
+                    deserialize: RenameRule::UpperCase,
+                }
+            ))
+        );
+    }
+}
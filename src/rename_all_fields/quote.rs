@@ -0,0 +1,27 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::RenameAllIndependent;
+
+use super::{RenameAllFields, RenameAllFieldsIndependent};
+
+/// Emits `rename_all_fields = "..."` or
+/// `rename_all_fields(serialize = "...", deserialize = "...")`, the meta `TryFrom<&Meta>` parses.
+impl ToTokens for RenameAllFields {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Normal(rule) => {
+                let rule = rule.to_rename_all_str();
+                quote!(rename_all_fields = #rule)
+            }
+            Self::Independent(independent) => quote!(rename_all_fields(#independent)),
+        })
+    }
+}
+
+/// Emits the nested `serialize = "...", deserialize = "..."` of `rename_all_fields(...)`.
+impl ToTokens for RenameAllFieldsIndependent {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        RenameAllIndependent::from(self.clone()).to_tokens(tokens)
+    }
+}
@@ -0,0 +1,29 @@
+use std::convert::TryFrom;
+
+use syn::Meta;
+
+use crate::RenameAll;
+
+pub use crate::rename_all::syn::FromMetaError;
+
+use super::RenameAllFields;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/symbol.rs#L27)
+pub const RENAME_ALL_FIELDS: &str = "rename_all_fields";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L303-L341)
+impl<'a> TryFrom<&'a Meta> for RenameAllFields {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        RenameAll::try_from_meta(meta, RENAME_ALL_FIELDS).map(Self::from)
+    }
+}
+
+/// Builds `rename_all_fields = "..."` or
+/// `rename_all_fields(serialize = "...", deserialize = "...")`.
+impl From<&RenameAllFields> for Meta {
+    fn from(rename_all_fields: &RenameAllFields) -> Self {
+        RenameAll::from(rename_all_fields.clone()).to_meta(RENAME_ALL_FIELDS)
+    }
+}
@@ -0,0 +1,21 @@
+use std::convert::TryFrom;
+
+use syn2::Meta;
+
+use crate::rename_all::syn2::try_from_meta as rename_all_try_from_meta;
+
+pub use crate::rename_all::syn2::FromMetaError;
+
+use super::RenameAllFields;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/symbol.rs#L27)
+pub const RENAME_ALL_FIELDS: &str = "rename_all_fields";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L303-L341)
+impl<'a> TryFrom<&'a Meta> for RenameAllFields {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        rename_all_try_from_meta(meta, RENAME_ALL_FIELDS).map(Self::from)
+    }
+}
@@ -0,0 +1,70 @@
+//! [Official doc](https://serde.rs/field-attrs.html#skip)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use ::syn::ExprPath;
+
+/// `skip`, `skip_serializing`, `skip_deserializing` and `skip_serializing_if = "..."`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Default)]
+pub struct Skip {
+    pub serializing: bool,
+    pub deserializing: bool,
+    pub serializing_if: Option<ExprPath>,
+}
+impl Skip {
+    /// Unconditionally skipped when serializing, `serializing_if` is not taken into account.
+    pub fn is_skipped_on_ser(&self) -> bool {
+        self.serializing
+    }
+
+    pub fn is_skipped_on_de(&self) -> bool {
+        self.deserializing
+    }
+
+    /// Combines two skips from separate attributes, `None` if both set the same direction.
+    pub fn merge(self, other: Self) -> Option<Self> {
+        if (self.serializing && other.serializing)
+            || (self.deserializing && other.deserializing)
+            || (self.serializing_if.is_some() && other.serializing_if.is_some())
+        {
+            return None;
+        }
+        Some(Self {
+            serializing: self.serializing || other.serializing,
+            deserializing: self.deserializing || other.deserializing,
+            serializing_if: self.serializing_if.or(other.serializing_if),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        let ser = Skip {
+            serializing: true,
+            ..Skip::default()
+        };
+        let de = Skip {
+            deserializing: true,
+            ..Skip::default()
+        };
+        let both = Skip {
+            serializing: true,
+            deserializing: true,
+            ..Skip::default()
+        };
+
+        assert_eq!(ser.clone().merge(de.clone()), Some(both.clone()));
+        assert!(both.is_skipped_on_ser());
+        assert!(both.is_skipped_on_de());
+        assert_eq!(ser.clone().merge(both.clone()), None);
+        assert_eq!(both.merge(de), None);
+        assert_eq!(ser.clone().merge(ser), None);
+    }
+}
@@ -0,0 +1,114 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn::{ExprPath, Lit, LitStr, Meta};
+
+use crate::{
+    attrs::{meta_name, meta_span},
+    Error,
+};
+
+use super::Skip;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L28)
+pub const SKIP: &str = "skip";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L30)
+pub const SKIP_SERIALIZING: &str = "skip_serializing";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L29)
+pub const SKIP_DESERIALIZING: &str = "skip_deserializing";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L31)
+pub const SKIP_SERIALIZING_IF: &str = "skip_serializing_if";
+
+/// Takes one of `skip`, `skip_serializing`, `skip_deserializing` or `skip_serializing_if`,
+/// use [`Skip::merge`] to combine them.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1222-L1244)
+impl<'a> TryFrom<&'a Meta> for Skip {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::Path(ref path) if path.is_ident(SKIP) => Ok(Self {
+                serializing: true,
+                deserializing: true,
+                ..Self::default()
+            }),
+            Meta::Path(ref path) if path.is_ident(SKIP_SERIALIZING) => Ok(Self {
+                serializing: true,
+                ..Self::default()
+            }),
+            Meta::Path(ref path) if path.is_ident(SKIP_DESERIALIZING) => Ok(Self {
+                deserializing: true,
+                ..Self::default()
+            }),
+            Meta::NameValue(ref meta_name_value)
+                if meta_name_value.path.is_ident(SKIP_SERIALIZING_IF) =>
+            {
+                match &meta_name_value.lit {
+                    Lit::Str(ref s) => match s.parse::<ExprPath>() {
+                        Ok(path) => Ok(Self {
+                            serializing_if: Some(path),
+                            ..Self::default()
+                        }),
+                        Err(err) => Err(FromMetaError::ExprPathParseFailed(s, err)),
+                    },
+                    lit => Err(FromMetaError::LitTypeMismatch(lit)),
+                }
+            }
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Lit),
+    ExprPathParseFailed(&'a LitStr, syn::Error),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1616-L1625)
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) if meta.path().is_ident(SKIP_SERIALIZING_IF) => {
+                Error::new(
+                    meta_span(meta),
+                    format_args!(
+                        r#"malformed {0} attribute, expected `{0} = "..."`"#,
+                        SKIP_SERIALIZING_IF
+                    ),
+                )
+            }
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!("malformed {0} attribute, expected `{0}`", meta_name(meta)),
+            ),
+            Self::LitTypeMismatch(lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    SKIP_SERIALIZING_IF
+                ),
+            ),
+            Self::ExprPathParseFailed(s, _) => Error::new(
+                s.span(),
+                format_args!("failed to parse path: {:?}", s.value()),
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_) => write!(f, "LitTypeMismatch"),
+            Self::ExprPathParseFailed(_, err) => write!(f, "ExprPathParseFailed({})", err),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
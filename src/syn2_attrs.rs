@@ -0,0 +1,41 @@
+//! Shared helpers for collecting `#[serde(...)]` attributes with syn 2.
+
+use syn2::{punctuated::Punctuated, Attribute, Meta, Token};
+#[cfg(any(feature = "attr-alias", feature = "attr-rename"))]
+use syn2::{Expr, ExprLit, Lit, LitStr};
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/symbol.rs#L29)
+pub const SERDE: &str = "serde";
+
+/// Flattens every `#[serde(...)]` attribute into its nested metas, skipping other attributes.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L262-L275)
+pub fn parse_serde_meta_list(attrs: &[Attribute]) -> syn2::Result<Vec<Meta>> {
+    let mut metas = vec![];
+    for attr in attrs {
+        if !attr.path().is_ident(SERDE) {
+            continue;
+        }
+
+        metas.extend(attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?);
+    }
+    Ok(metas)
+}
+
+/// Returns the string literal of `name = "..."`, looking through the invisible groups
+/// `macro_rules!` wraps around interpolated expressions.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/attr.rs#L1421-L1455)
+#[cfg(any(feature = "attr-alias", feature = "attr-rename"))]
+pub(crate) fn expr_lit_str(expr: &Expr) -> Option<&LitStr> {
+    let mut expr = expr;
+    while let Expr::Group(group) = expr {
+        expr = &group.expr;
+    }
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Some(s),
+        _ => None,
+    }
+}
@@ -0,0 +1,10 @@
+//! [Official doc](https://serde.rs/container-attrs.html#transparent)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+/// `#[serde(transparent)]`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct Transparent;
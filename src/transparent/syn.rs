@@ -0,0 +1,229 @@
+use std::{convert::TryFrom, error, fmt};
+
+use proc_macro2::Span;
+use syn::{Data, DeriveInput, Fields, Meta, Type};
+
+use crate::{
+    attrs::{meta_span, parse_serde_meta_list, FromAttrsError},
+    Error,
+};
+
+use super::Transparent;
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L33)
+pub const TRANSPARENT: &str = "transparent";
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L366-L369)
+impl<'a> TryFrom<&'a Meta> for Transparent {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match meta {
+            Meta::Path(ref path) if path.is_ident(TRANSPARENT) => Ok(Self),
+            meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message, `meta` being the one that failed.
+    pub fn into_error(self, meta: &Meta) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => Error::new(
+                meta_span(meta),
+                format_args!(r#"malformed {0} attribute, expected `{0}`"#, TRANSPARENT),
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
+
+/// Fields that never count as the `transparent` one for `Serialize`.
+const SER_SKIP_ATTRS: &[&str] = &["skip", "skip_serializing"];
+/// Fields that never count as the `transparent` one for `Deserialize`.
+const DE_SKIP_ATTRS: &[&str] = &["skip", "skip_deserializing", "default"];
+
+/// The index of the one field each derive of a `transparent` struct (de)serializes as, or
+/// why that derive rejects the struct.
+///
+/// serde checks `Serialize` and `Deserialize` separately, so a struct may be fine for one
+/// and rejected by the other.
+#[derive(Debug)]
+pub struct TransparentFields {
+    pub serialize: Result<usize, CheckTransparentError>,
+    pub deserialize: Result<usize, CheckTransparentError>,
+}
+
+/// Finds the `transparent` field of each derive, `None` if the container is not
+/// `transparent`.
+///
+/// `PhantomData` fields never count. Neither do `skip` and `skip_serializing` fields for
+/// `Serialize`, nor `skip`, `skip_deserializing` and `default` fields for `Deserialize`.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/check.rs#L311-L411)
+pub fn check_transparent(
+    input: &DeriveInput,
+) -> Result<Option<TransparentFields>, CheckTransparentError> {
+    let transparent = match parse_serde_meta_list(&input.attrs)?
+        .iter()
+        .find(|meta| meta.path().is_ident(TRANSPARENT))
+    {
+        Some(meta) => meta_span(meta),
+        None => return Ok(None),
+    };
+
+    let fields = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Unit => return Err(CheckTransparentError::UnitStruct(transparent)),
+            fields => fields,
+        },
+        Data::Enum(_) | Data::Union(_) => {
+            return Err(CheckTransparentError::NotAStruct(transparent))
+        }
+    };
+
+    let mut ser_candidates = vec![];
+    let mut de_candidates = vec![];
+    for (index, field) in fields.iter().enumerate() {
+        if is_phantom_data(&field.ty) {
+            continue;
+        }
+        let metas = parse_serde_meta_list(&field.attrs)?;
+        let has_any = |names: &[&str]| {
+            metas
+                .iter()
+                .any(|meta| names.iter().any(|name| meta.path().is_ident(name)))
+        };
+        if !has_any(SER_SKIP_ATTRS) {
+            ser_candidates.push(index);
+        }
+        if !has_any(DE_SKIP_ATTRS) {
+            de_candidates.push(index);
+        }
+    }
+
+    Ok(Some(TransparentFields {
+        serialize: only_candidate(
+            transparent,
+            &ser_candidates,
+            CheckTransparentError::NoSerializeField,
+        ),
+        deserialize: only_candidate(
+            transparent,
+            &de_candidates,
+            CheckTransparentError::NoDeserializeField,
+        ),
+    }))
+}
+
+fn only_candidate(
+    transparent: Span,
+    candidates: &[usize],
+    no_field: fn(Span) -> CheckTransparentError,
+) -> Result<usize, CheckTransparentError> {
+    match *candidates {
+        [] => Err(no_field(transparent)),
+        [index] => Ok(index),
+        [first, second, ..] => Err(CheckTransparentError::MoreThanOneField(
+            transparent,
+            first,
+            second,
+        )),
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/check.rs#L398-L404)
+fn is_phantom_data(ty: &Type) -> bool {
+    let mut ty = ty;
+    while let Type::Group(group) = ty {
+        ty = &group.elem;
+    }
+    match ty {
+        Type::Path(type_path) => match type_path.path.segments.last() {
+            Some(segment) => segment.ident == "PhantomData",
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// The `Span` points at the `transparent` attribute.
+pub enum CheckTransparentError {
+    FromAttrsFailed(FromAttrsError),
+    NotAStruct(Span),
+    UnitStruct(Span),
+    NoSerializeField(Span),
+    NoDeserializeField(Span),
+    /// The indexes of the first two fields that are not skipped.
+    MoreThanOneField(Span, usize, usize),
+}
+impl From<FromAttrsError> for CheckTransparentError {
+    fn from(err: FromAttrsError) -> Self {
+        Self::FromAttrsFailed(err)
+    }
+}
+impl fmt::Debug for CheckTransparentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "FromAttrsFailed({:?})", err),
+            Self::NotAStruct(_) => write!(f, "NotAStruct"),
+            Self::UnitStruct(_) => write!(f, "UnitStruct"),
+            Self::NoSerializeField(_) => write!(f, "NoSerializeField"),
+            Self::NoDeserializeField(_) => write!(f, "NoDeserializeField"),
+            Self::MoreThanOneField(_, first, second) => {
+                write!(f, "MoreThanOneField({}, {})", first, second)
+            }
+        }
+    }
+}
+impl fmt::Display for CheckTransparentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(err) => write!(f, "{}", err),
+            Self::NotAStruct(_) => write!(f, "#[serde(transparent)] is not allowed on an enum"),
+            Self::UnitStruct(_) => {
+                write!(f, "#[serde(transparent)] is not allowed on a unit struct")
+            }
+            Self::NoSerializeField(_) => write!(
+                f,
+                "#[serde(transparent)] requires at least one field that is not skipped"
+            ),
+            Self::NoDeserializeField(_) => write!(
+                f,
+                "#[serde(transparent)] requires at least one field that is neither skipped nor has a default"
+            ),
+            Self::MoreThanOneField(_, _, _) => write!(
+                f,
+                "#[serde(transparent)] requires struct to have at most one transparent field"
+            ),
+        }
+    }
+}
+impl error::Error for CheckTransparentError {}
+impl From<CheckTransparentError> for Error {
+    fn from(err: CheckTransparentError) -> Self {
+        match err {
+            CheckTransparentError::FromAttrsFailed(err) => Self::from(err),
+            CheckTransparentError::NotAStruct(span)
+            | CheckTransparentError::UnitStruct(span)
+            | CheckTransparentError::NoSerializeField(span)
+            | CheckTransparentError::NoDeserializeField(span)
+            | CheckTransparentError::MoreThanOneField(span, _, _) => Self::new(span, err),
+        }
+    }
+}
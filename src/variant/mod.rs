@@ -0,0 +1,47 @@
+//! [Official doc](https://serde.rs/variant-attrs.html)
+
+pub mod syn;
+
+#[cfg(feature = "attr-alias")]
+use crate::Alias;
+#[cfg(feature = "attr-borrow")]
+use crate::Borrow;
+#[cfg(feature = "attr-bound")]
+use crate::Bound;
+#[cfg(feature = "attr-identifier")]
+use crate::Other;
+#[cfg(feature = "attr-rename")]
+use crate::Rename;
+#[cfg(feature = "attr-rename-all")]
+use crate::RenameAll;
+#[cfg(feature = "attr-skip")]
+use crate::Skip;
+#[cfg(feature = "attr-enum-repr")]
+use crate::Untagged;
+#[cfg(feature = "attr-with")]
+use crate::With;
+
+/// All `#[serde(...)]` attributes of an enum variant.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Variant {
+    #[cfg(feature = "attr-rename")]
+    pub rename: Option<Rename>,
+    #[cfg(feature = "attr-alias")]
+    pub alias: Vec<Alias>,
+    #[cfg(feature = "attr-rename-all")]
+    pub rename_all: Option<RenameAll>,
+    #[cfg(feature = "attr-enum-repr")]
+    pub untagged: Option<Untagged>,
+    /// Never has `serializing_if`, serde only accepts `skip_serializing_if` on fields.
+    #[cfg(feature = "attr-skip")]
+    pub skip: Option<Skip>,
+    #[cfg(feature = "attr-with")]
+    pub with: Option<With>,
+    #[cfg(feature = "attr-bound")]
+    pub bound: Option<Bound>,
+    /// Only valid on newtype variants, see `borrow::syn::check_borrow`.
+    #[cfg(feature = "attr-borrow")]
+    pub borrow: Option<Borrow>,
+    #[cfg(feature = "attr-identifier")]
+    pub other: Option<Other>,
+}
@@ -0,0 +1,259 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn::{Attribute, DataEnum, Ident, Variant as SynVariant};
+
+#[cfg(any(feature = "attr-enum-repr", feature = "attr-borrow"))]
+use crate::attrs::set_once;
+#[cfg(any(
+    feature = "attr-rename",
+    feature = "attr-rename-all",
+    feature = "attr-skip",
+    feature = "attr-bound"
+))]
+use crate::attrs::set_or_merge;
+use crate::attrs::{ok_or_combined, parse_serde_meta_list, FromAttrsError};
+#[cfg(feature = "attr-with")]
+use crate::with::{
+    self,
+    syn::{DESERIALIZE_WITH, SERIALIZE_WITH, WITH},
+};
+use crate::Error;
+#[cfg(feature = "attr-alias")]
+use crate::{alias::syn::ALIAS, Alias};
+#[cfg(feature = "attr-borrow")]
+use crate::{borrow::syn::BORROW, Borrow};
+#[cfg(feature = "attr-bound")]
+use crate::{bound::syn::BOUND, Bound};
+#[cfg(feature = "attr-enum-repr")]
+use crate::{enum_repr::syn::UNTAGGED, Untagged};
+#[cfg(feature = "attr-identifier")]
+use crate::{identifier::syn::OTHER, Other};
+#[cfg(feature = "attr-rename")]
+use crate::{rename::syn::RENAME, Rename};
+#[cfg(feature = "attr-rename-all")]
+use crate::{rename_all::syn::RENAME_ALL, RenameAll};
+#[cfg(feature = "attr-skip")]
+use crate::{
+    skip::syn::{SKIP, SKIP_DESERIALIZING, SKIP_SERIALIZING},
+    Skip,
+};
+
+use super::Variant;
+
+/// Every variant attribute serde knows about.
+///
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L859-L872)
+pub const VARIANT_ATTRS: &[&str] = &[
+    "rename",
+    "alias",
+    "rename_all",
+    "skip",
+    "skip_deserializing",
+    "skip_serializing",
+    "other",
+    "bound",
+    "with",
+    "serialize_with",
+    "deserialize_with",
+    "borrow",
+    "untagged",
+];
+
+impl Variant {
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L859-L1080)
+    pub fn try_from_attrs(attrs: &[Attribute]) -> Result<Self, FromAttrsError> {
+        #[allow(unused_mut)]
+        let mut variant = Self::default();
+        let mut errors = vec![];
+        #[cfg(feature = "attr-with")]
+        let mut with_metas = vec![];
+
+        for meta in parse_serde_meta_list(attrs)? {
+            #[cfg(feature = "attr-rename")]
+            if meta.path().is_ident(RENAME) {
+                let result = match Rename::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(rename) => set_or_merge(&mut variant.rename, rename, meta, Rename::merge),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-alias")]
+            if meta.path().is_ident(ALIAS) {
+                let result = match Alias::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(alias) => {
+                        variant.alias.push(alias);
+                        Ok(())
+                    }
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-rename-all")]
+            if meta.path().is_ident(RENAME_ALL) {
+                let result = match RenameAll::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(rename_all) => {
+                        set_or_merge(&mut variant.rename_all, rename_all, meta, RenameAll::merge)
+                    }
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-skip")]
+            if [SKIP, SKIP_SERIALIZING, SKIP_DESERIALIZING]
+                .iter()
+                .any(|name| meta.path().is_ident(name))
+            {
+                let result = match Skip::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(skip) => set_or_merge(&mut variant.skip, skip, meta, Skip::merge),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-with")]
+            if [WITH, SERIALIZE_WITH, DESERIALIZE_WITH]
+                .iter()
+                .any(|name| meta.path().is_ident(name))
+            {
+                with_metas.push(meta);
+                continue;
+            }
+
+            #[cfg(feature = "attr-bound")]
+            if meta.path().is_ident(BOUND) {
+                let result = match Bound::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(bound) => set_or_merge(&mut variant.bound, bound, meta, Bound::merge),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-borrow")]
+            if meta.path().is_ident(BORROW) {
+                let result = match Borrow::try_from(&meta).map_err(|err| err.into_error(&meta)) {
+                    Ok(borrow) => set_once(&mut variant.borrow, borrow, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-enum-repr")]
+            if meta.path().is_ident(UNTAGGED) {
+                let result = match Untagged::try_from(&meta).map_err(|err| err.into_error()) {
+                    Ok(untagged) => set_once(&mut variant.untagged, untagged, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            #[cfg(feature = "attr-identifier")]
+            if meta.path().is_ident(OTHER) {
+                let result = match Other::try_from(&meta).map_err(|err| err.into_error()) {
+                    Ok(other) => set_once(&mut variant.other, other, meta),
+                    Err(err) => Err(FromAttrsError::InvalidAttribute(meta, err)),
+                };
+                errors.extend(result.err());
+                continue;
+            }
+
+            if !VARIANT_ATTRS.iter().any(|name| meta.path().is_ident(name)) {
+                errors.push(FromAttrsError::UnknownAttribute(meta));
+            }
+        }
+
+        #[cfg(feature = "attr-with")]
+        {
+            match with::syn::try_from_attr_metas(&with_metas) {
+                Ok(with) => variant.with = with,
+                Err(err) => errors.push(err),
+            }
+        }
+
+        ok_or_combined(variant, errors)
+    }
+
+    /// Parses every variant of the enum, checking that untagged variants come last.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.228/serde_derive/src/internals/ast.rs#L160-L169)
+    pub fn try_from_data_enum(data_enum: &DataEnum) -> Result<Vec<Self>, FromDataEnumError> {
+        let variants = data_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                Self::try_from(variant).map_err(|err| {
+                    FromDataEnumError::FromAttrsFailed(variant.ident.clone(), Box::new(err))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        #[cfg(feature = "attr-enum-repr")]
+        if let Some(index_of_last_tagged_variant) = variants
+            .iter()
+            .rposition(|variant| variant.untagged.is_none())
+        {
+            if let Some((variant, _)) = data_enum
+                .variants
+                .iter()
+                .zip(&variants)
+                .take(index_of_last_tagged_variant)
+                .find(|(_, variant)| variant.untagged.is_some())
+            {
+                return Err(FromDataEnumError::UntaggedNotAtEnd(variant.ident.clone()));
+            }
+        }
+
+        Ok(variants)
+    }
+}
+
+impl<'a> TryFrom<&'a SynVariant> for Variant {
+    type Error = FromAttrsError;
+
+    fn try_from(variant: &'a SynVariant) -> Result<Self, Self::Error> {
+        Self::try_from_attrs(&variant.attrs)
+    }
+}
+
+/// The `Ident` points at the offending variant.
+pub enum FromDataEnumError {
+    FromAttrsFailed(Ident, Box<FromAttrsError>),
+    UntaggedNotAtEnd(Ident),
+}
+impl fmt::Debug for FromDataEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FromAttrsFailed(ident, err) => write!(f, "FromAttrsFailed({}, {:?})", ident, err),
+            Self::UntaggedNotAtEnd(ident) => write!(f, "UntaggedNotAtEnd({})", ident),
+        }
+    }
+}
+impl fmt::Display for FromDataEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UntaggedNotAtEnd(_) => write!(
+                f,
+                "all variants with the #[serde(untagged)] attribute must be placed at the end of the enum"
+            ),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+impl error::Error for FromDataEnumError {}
+impl From<FromDataEnumError> for Error {
+    fn from(err: FromDataEnumError) -> Self {
+        match err {
+            FromDataEnumError::FromAttrsFailed(_, err) => Self::from(*err),
+            FromDataEnumError::UntaggedNotAtEnd(ref ident) => Self::new(ident.span(), &err),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::convert::TryFrom;
+
+use darling_core::{Error as DarlingError, FromMeta};
+use syn::{Meta, NestedMeta};
+
+use crate::attrs::SERDE;
+
+use super::{syn::FromMetaError, With};
+
+fn map_err(err: FromMetaError<'_>) -> DarlingError {
+    match err {
+        FromMetaError::MetaTypeOrPathMismatch(meta) => match meta {
+            Meta::Path(path) => DarlingError::unknown_field_path(path),
+            Meta::List(meta_list) => DarlingError::unknown_field_path(&meta_list.path),
+            Meta::NameValue(meta_name_value) => {
+                DarlingError::unknown_field_path(&meta_name_value.path)
+            }
+        },
+        FromMetaError::LitTypeMismatch(_, lit) => DarlingError::unexpected_lit_type(lit),
+        FromMetaError::ExprPathParseFailed(_, s, err) => DarlingError::custom(err).with_span(s),
+        FromMetaError::DuplicateMeta(meta) => DarlingError::duplicate_field_path(meta.path()),
+        FromMetaError::ModuleConflict(_, meta) => module_conflict().with_span(meta),
+    }
+}
+
+/// What [`FromMetaError::ModuleConflict`] maps to.
+fn module_conflict() -> DarlingError {
+    DarlingError::custom("with cannot be used together with serialize_with or deserialize_with")
+}
+
+impl FromMeta for With {
+    /// A single `with`, `serialize_with` or `deserialize_with`, or a whole `serde(...)` list.
+    fn from_meta(meta: &Meta) -> Result<Self, DarlingError> {
+        match meta {
+            Meta::List(meta_list) if meta_list.path.is_ident(SERDE) => {
+                Self::from_list(&meta_list.nested.iter().cloned().collect::<Vec<_>>()[..])
+            }
+            meta => Self::try_from(meta).map_err(map_err),
+        }
+    }
+
+    /// The items of a `#[serde(...)]` list, e.g. `#[serde(serialize_with = "a", deserialize_with = "b")]`.
+    fn from_list(items: &[NestedMeta]) -> Result<Self, DarlingError> {
+        let metas = items
+            .iter()
+            .map(|nested_meta| match nested_meta {
+                NestedMeta::Meta(meta) => Ok(meta.to_owned()),
+                NestedMeta::Lit(lit) => Err(DarlingError::unexpected_lit_type(lit)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::try_from_metas(&metas[..])
+            .map_err(map_err)?
+            .ok_or_else(|| {
+                DarlingError::custom("expected with, serialize_with or deserialize_with")
+            })
+    }
+}
+
+impl With {
+    /// Combines the `with`, `serialize_with` and `deserialize_with` fields of a darling struct,
+    /// each parsed on its own, the way [`With::try_from_metas`] does for the metas.
+    pub fn from_darling_fields(
+        with: Option<Self>,
+        serialize_with: Option<Self>,
+        deserialize_with: Option<Self>,
+    ) -> Result<Option<Self>, DarlingError> {
+        match (with, serialize_with, deserialize_with) {
+            (Some(_), Some(other), _) | (Some(_), None, Some(other)) => {
+                let err = module_conflict();
+                Err(match other.ser_fn().or_else(|| other.de_fn()) {
+                    Some(path) => err.with_span(&path),
+                    None => err,
+                })
+            }
+            (with, None, None) => Ok(with),
+            (None, Some(with), None) | (None, None, Some(with)) => Ok(Some(with)),
+            (None, Some(serialize_with), Some(deserialize_with)) => serialize_with
+                .merge(deserialize_with)
+                .map(Some)
+                .ok_or_else(|| {
+                    DarlingError::custom(
+                        "serialize_with and deserialize_with must set different sides",
+                    )
+                }),
+        }
+    }
+}
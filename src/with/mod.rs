@@ -0,0 +1,131 @@
+//! [Official doc](https://serde.rs/field-attrs.html#with)
+
+#[cfg(feature = "with-darling")]
+pub mod darling;
+#[cfg(feature = "with-syn")]
+pub mod syn;
+
+use ::syn::{ExprPath, Ident};
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum With {
+    /// `with = "module"`, meaning `module::serialize` and `module::deserialize`.
+    Module(ExprPath),
+    Independent(WithIndependent),
+}
+/// `serialize_with = "..."` and/or `deserialize_with = "..."`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum WithIndependent {
+    Serialize(ExprPath),
+    Deserialize(ExprPath),
+    Both {
+        serialize: ExprPath,
+        deserialize: ExprPath,
+    },
+}
+impl With {
+    pub fn ser_fn(&self) -> Option<ExprPath> {
+        match self {
+            Self::Module(module) => Some(push_segment(module.to_owned(), "serialize")),
+            Self::Independent(WithIndependent::Serialize(path))
+            | Self::Independent(WithIndependent::Both {
+                serialize: path,
+                deserialize: _,
+            }) => Some(path.to_owned()),
+            _ => None,
+        }
+    }
+
+    pub fn de_fn(&self) -> Option<ExprPath> {
+        match self {
+            Self::Module(module) => Some(push_segment(module.to_owned(), "deserialize")),
+            Self::Independent(WithIndependent::Deserialize(path))
+            | Self::Independent(WithIndependent::Both {
+                serialize: _,
+                deserialize: path,
+            }) => Some(path.to_owned()),
+            _ => None,
+        }
+    }
+
+    /// Combines `serialize_with` and `deserialize_with` given apart, e.g.
+    /// `#[serde(serialize_with = "a")] #[serde(deserialize_with = "b")]`.
+    ///
+    /// Returns `None` if both set the same side or either is `with`.
+    pub fn merge(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (
+                Self::Independent(WithIndependent::Serialize(serialize)),
+                Self::Independent(WithIndependent::Deserialize(deserialize)),
+            )
+            | (
+                Self::Independent(WithIndependent::Deserialize(deserialize)),
+                Self::Independent(WithIndependent::Serialize(serialize)),
+            ) => Some(Self::Independent(WithIndependent::Both {
+                serialize,
+                deserialize,
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1259-L1275)
+fn push_segment(mut path: ExprPath, name: &str) -> ExprPath {
+    let span = path
+        .path
+        .segments
+        .last()
+        .map(|segment| segment.ident.span())
+        .expect("ExprPath has at least one segment");
+    path.path.segments.push(Ident::new(name, span).into());
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ::syn::parse_quote;
+
+    #[test]
+    fn test_ser_fn_and_de_fn() {
+        let with = With::Module(parse_quote!(foo::bar));
+        assert_eq!(with.ser_fn(), Some(parse_quote!(foo::bar::serialize)));
+        assert_eq!(with.de_fn(), Some(parse_quote!(foo::bar::deserialize)));
+
+        let with = With::Independent(WithIndependent::Serialize(parse_quote!(ser)));
+        assert_eq!(with.ser_fn(), Some(parse_quote!(ser)));
+        assert_eq!(with.de_fn(), None);
+
+        let with = With::Independent(WithIndependent::Deserialize(parse_quote!(de)));
+        assert_eq!(with.ser_fn(), None);
+        assert_eq!(with.de_fn(), Some(parse_quote!(de)));
+
+        let with = With::Independent(WithIndependent::Both {
+            serialize: parse_quote!(ser),
+            deserialize: parse_quote!(de),
+        });
+        assert_eq!(with.ser_fn(), Some(parse_quote!(ser)));
+        assert_eq!(with.de_fn(), Some(parse_quote!(de)));
+    }
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(
+            With::Independent(WithIndependent::Deserialize(parse_quote!(de))).merge(
+                With::Independent(WithIndependent::Serialize(parse_quote!(ser)))
+            ),
+            Some(With::Independent(WithIndependent::Both {
+                serialize: parse_quote!(ser),
+                deserialize: parse_quote!(de),
+            }))
+        );
+        assert_eq!(
+            With::Module(parse_quote!(foo)).merge(With::Independent(WithIndependent::Serialize(
+                parse_quote!(ser)
+            ))),
+            None
+        );
+    }
+}
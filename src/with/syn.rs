@@ -0,0 +1,163 @@
+use std::{convert::TryFrom, error, fmt};
+
+use syn::{ExprPath, Lit, LitStr, Meta};
+
+use crate::{
+    attrs::{meta_name, meta_span, FromAttrsError},
+    Error,
+};
+
+use super::{With, WithIndependent};
+
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L37)
+pub const WITH: &str = "with";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L27)
+pub const SERIALIZE_WITH: &str = "serialize_with";
+/// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/symbol.rs#L15)
+pub const DESERIALIZE_WITH: &str = "deserialize_with";
+
+impl With {
+    /// Takes the metas of a field's or variant's `#[serde(...)]` lists, ignoring the ones that are
+    /// not `with`, `serialize_with` or `deserialize_with`.
+    ///
+    /// [Ref](https://github.com/serde-rs/serde/blob/v1.0.127/serde_derive/src/internals/attr.rs#L1245-L1275)
+    pub fn try_from_metas<'a>(metas: &'a [Meta]) -> Result<Option<Self>, FromMetaError<'a>> {
+        let mut module: Option<(&'a Meta, ExprPath)> = None;
+        let mut ser_path: Option<(&'a Meta, ExprPath)> = None;
+        let mut de_path: Option<(&'a Meta, ExprPath)> = None;
+
+        for meta in metas {
+            let slot = if meta.path().is_ident(WITH) {
+                &mut module
+            } else if meta.path().is_ident(SERIALIZE_WITH) {
+                &mut ser_path
+            } else if meta.path().is_ident(DESERIALIZE_WITH) {
+                &mut de_path
+            } else {
+                continue;
+            };
+            let path = parse_expr_path(meta)?;
+            if slot.is_some() {
+                return Err(FromMetaError::DuplicateMeta(meta));
+            }
+            *slot = Some((meta, path));
+        }
+
+        match (module, ser_path, de_path) {
+            (Some((module_meta, _)), Some((meta, _)), _)
+            | (Some((module_meta, _)), None, Some((meta, _))) => {
+                Err(FromMetaError::ModuleConflict(module_meta, meta))
+            }
+            (Some((_, module)), None, None) => Ok(Some(Self::Module(module))),
+            (None, Some((_, ser_path)), None) => Ok(Some(Self::Independent(
+                WithIndependent::Serialize(ser_path),
+            ))),
+            (None, None, Some((_, de_path))) => Ok(Some(Self::Independent(
+                WithIndependent::Deserialize(de_path),
+            ))),
+            (None, Some((_, ser_path)), Some((_, de_path))) => {
+                Ok(Some(Self::Independent(WithIndependent::Both {
+                    serialize: ser_path,
+                    deserialize: de_path,
+                })))
+            }
+            (None, None, None) => Ok(None),
+        }
+    }
+}
+
+/// [`With::try_from_metas`] for the `Field` and `Variant` aggregates.
+pub(crate) fn try_from_attr_metas(metas: &[Meta]) -> Result<Option<With>, FromAttrsError> {
+    With::try_from_metas(metas).map_err(|err| match err {
+        FromMetaError::DuplicateMeta(meta) => FromAttrsError::DuplicateAttribute(meta.to_owned()),
+        FromMetaError::MetaTypeOrPathMismatch(meta)
+        | FromMetaError::LitTypeMismatch(meta, _)
+        | FromMetaError::ExprPathParseFailed(meta, _, _)
+        | FromMetaError::ModuleConflict(_, meta) => {
+            FromAttrsError::InvalidAttribute(meta.to_owned(), err.into_error())
+        }
+    })
+}
+
+/// Takes a single `with`, `serialize_with` or `deserialize_with`.
+impl<'a> TryFrom<&'a Meta> for With {
+    type Error = FromMetaError<'a>;
+
+    fn try_from(meta: &'a Meta) -> Result<Self, Self::Error> {
+        match Self::try_from_metas(std::slice::from_ref(meta))? {
+            Some(with) => Ok(with),
+            None => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+        }
+    }
+}
+
+fn parse_expr_path(meta: &Meta) -> Result<ExprPath, FromMetaError<'_>> {
+    match meta {
+        Meta::NameValue(ref meta_name_value) => match &meta_name_value.lit {
+            Lit::Str(ref s) => s
+                .parse::<ExprPath>()
+                .map_err(|err| FromMetaError::ExprPathParseFailed(meta, s, err)),
+            lit => Err(FromMetaError::LitTypeMismatch(meta, lit)),
+        },
+        meta => Err(FromMetaError::MetaTypeOrPathMismatch(meta)),
+    }
+}
+
+pub enum FromMetaError<'a> {
+    MetaTypeOrPathMismatch(&'a Meta),
+    LitTypeMismatch(&'a Meta, &'a Lit),
+    ExprPathParseFailed(&'a Meta, &'a LitStr, syn::Error),
+    DuplicateMeta(&'a Meta),
+    /// `with` together with `serialize_with` or `deserialize_with`.
+    ModuleConflict(&'a Meta, &'a Meta),
+}
+impl<'a> FromMetaError<'a> {
+    /// Turns this into an owned [`Error`] with serde's message.
+    pub fn into_error(self) -> Error {
+        match self {
+            Self::MetaTypeOrPathMismatch(meta) => Error::new(
+                meta_span(meta),
+                format_args!(
+                    r#"malformed {0} attribute, expected `{0} = "..."`"#,
+                    meta_name(meta)
+                ),
+            ),
+            Self::LitTypeMismatch(meta, lit) => Error::new(
+                lit.span(),
+                format_args!(
+                    r#"expected serde {0} attribute to be a string: `{0} = "..."`"#,
+                    meta_name(meta)
+                ),
+            ),
+            Self::ExprPathParseFailed(_, s, _) => Error::new(
+                s.span(),
+                format_args!("failed to parse path: {:?}", s.value()),
+            ),
+            Self::DuplicateMeta(meta) => Error::new(
+                meta_span(meta),
+                format_args!("duplicate serde attribute `{}`", meta_name(meta)),
+            ),
+            Self::ModuleConflict(_, meta) => Error::new(
+                meta_span(meta),
+                format_args!("duplicate serde attribute `{}`", meta_name(meta)),
+            ),
+        }
+    }
+}
+impl<'a> fmt::Debug for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MetaTypeOrPathMismatch(_) => write!(f, "MetaTypeOrPathMismatch"),
+            Self::LitTypeMismatch(_, _) => write!(f, "LitTypeMismatch"),
+            Self::ExprPathParseFailed(_, _, err) => write!(f, "ExprPathParseFailed({})", err),
+            Self::DuplicateMeta(_) => write!(f, "DuplicateMeta"),
+            Self::ModuleConflict(_, _) => write!(f, "ModuleConflict"),
+        }
+    }
+}
+impl<'a> fmt::Display for FromMetaError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+impl<'a> error::Error for FromMetaError<'a> {}
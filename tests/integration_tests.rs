@@ -6,5 +6,7 @@ mod integration_tests {
     #[cfg(test)]
     mod field_attrs;
     #[cfg(test)]
+    mod name;
+    #[cfg(test)]
     mod variant_attrs;
 }
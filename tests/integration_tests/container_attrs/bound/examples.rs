@@ -0,0 +1,14 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound = "T: serde::Serialize + serde::de::DeserializeOwned")]
+pub struct FooA<T>(pub T);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound(
+    serialize = "T: serde::Serialize",
+    deserialize = "T: serde::de::DeserializeOwned"
+))]
+pub struct FooB<T>(pub T);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound = "")]
+pub struct FooC<T>(pub std::marker::PhantomData<T>);
@@ -0,0 +1,91 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{
+    bound::{syn::FromMetaError, with_where_predicates},
+    Bound, BoundIndependent, Container,
+};
+use syn::{parse_quote, parse_str, DeriveInput, Generics, WherePredicate};
+
+use super::{parse_darling_bound, parse_serde_meta};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=2].join("\r\n");
+    let bound = Bound::try_from(&parse_serde_meta(&input)).unwrap();
+    let predicates: Vec<WherePredicate> =
+        vec![parse_quote!(T: serde::Serialize + serde::de::DeserializeOwned)];
+    assert_eq!(bound, Bound::Normal(predicates.clone()));
+    assert_eq!(bound.ser_bound(), Some(&predicates[..]));
+    assert_eq!(bound.de_bound(), Some(&predicates[..]));
+    assert_eq!(parse_darling_bound(&input).unwrap(), bound);
+    assert_eq!(
+        Container::try_from(&parse_str::<DeriveInput>(&input).unwrap())
+            .unwrap()
+            .bound,
+        Some(bound)
+    );
+
+    let input = lines[4..=9].join("\r\n");
+    let bound = Bound::try_from(&parse_serde_meta(&input)).unwrap();
+    assert_eq!(
+        bound,
+        Bound::Independent(BoundIndependent::Both {
+            serialize: vec![parse_quote!(T: serde::Serialize)],
+            deserialize: vec![parse_quote!(T: serde::de::DeserializeOwned)],
+        })
+    );
+    assert_eq!(parse_darling_bound(&input).unwrap(), bound);
+
+    let derive_input = parse_str::<DeriveInput>(&input).unwrap();
+    let generics = with_where_predicates(&derive_input.generics, bound.ser_bound().unwrap());
+    let expected: Generics = parse_quote!(<T>);
+    assert_eq!(generics.params, expected.params);
+    assert_eq!(
+        generics.where_clause,
+        Some(parse_quote!(where T: serde::Serialize))
+    );
+
+    let input = lines[11..=13].join("\r\n");
+    let bound = Bound::try_from(&parse_serde_meta(&input)).unwrap();
+    assert_eq!(bound, Bound::Normal(vec![]));
+    assert_eq!(parse_darling_bound(&input).unwrap(), bound);
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound = "T: +")]
+    pub struct Foo<T>(pub T);
+    "#;
+    match Bound::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::WherePredicatesParseFailed(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_bound(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound())]
+    pub struct Foo<T>(pub T);
+    "#;
+    match Bound::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::AtLeastOneOfSerAndDe) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_bound(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(bound = "T: Clone", bound(serialize = "T: Copy"))]
+    pub struct Foo<T>(pub T);
+    "#;
+    assert!(Container::try_from(&parse_str::<DeriveInput>(input).unwrap()).is_err());
+}
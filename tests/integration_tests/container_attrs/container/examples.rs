@@ -0,0 +1,21 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "name", rename_all = "snake_case")]
+pub struct FooA {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename(serialize = "ser_name"))]
+#[serde(rename(deserialize = "de_name"))]
+#[serde(deny_unknown_fields)]
+pub struct FooB {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooC {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "t")]
+#[serde(content = "c")]
+pub enum FooD {}
+
+#[derive(serde::Serialize, serde::Deserialize, Default)]
+#[serde(default)]
+pub struct FooE {}
@@ -0,0 +1,315 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{
+    attrs::FromAttrsError, default::Default as DefaultAttr,
+    rename_all::serde_rename_rule::RenameRule, Container, DenyUnknownFields, EnumRepr, Error,
+    Rename, RenameAll, RenameIndependent,
+};
+use syn::{parse_str, DeriveInput};
+
+fn parse_container(input: &str) -> Result<Container, FromAttrsError> {
+    Container::try_from(&parse_str::<DeriveInput>(input).unwrap())
+}
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=2].join("\r\n");
+    assert_eq!(
+        parse_container(&input).unwrap(),
+        Container {
+            rename: Some(Rename::Normal("name".to_owned())),
+            rename_all: Some(RenameAll::Normal(RenameRule::SnakeCase)),
+            ..Container::default()
+        }
+    );
+
+    let input = lines[4..=8].join("\r\n");
+    assert_eq!(
+        parse_container(&input).unwrap(),
+        Container {
+            rename: Some(Rename::Independent(RenameIndependent::Both {
+                serialize: "ser_name".to_owned(),
+                deserialize: "de_name".to_owned()
+            })),
+            deny_unknown_fields: Some(DenyUnknownFields),
+            ..Container::default()
+        }
+    );
+
+    let input = lines[10..=11].join("\r\n");
+    assert_eq!(parse_container(&input).unwrap(), Container::default());
+
+    let input = lines[13..=16].join("\r\n");
+    assert_eq!(
+        parse_container(&input).unwrap(),
+        Container {
+            enum_repr: EnumRepr::Adjacent {
+                tag: "t".to_owned(),
+                content: "c".to_owned()
+            },
+            ..Container::default()
+        }
+    );
+
+    let input = lines[18..=20].join("\r\n");
+    assert_eq!(
+        parse_container(&input).unwrap(),
+        Container {
+            default: Some(DefaultAttr::Default),
+            ..Container::default()
+        }
+    );
+}
+
+#[test]
+fn with_invalid() {
+    match parse_container(
+        r#"
+    #[serde(rename = "a")]
+    #[serde(rename = "b")]
+    pub struct Foo {}
+    "#,
+    ) {
+        Err(FromAttrsError::DuplicateAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_container(
+        r#"
+    #[serde(rename(serialize = "a"), rename = "b")]
+    pub struct Foo {}
+    "#,
+    ) {
+        Err(FromAttrsError::DuplicateAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_container(
+        r#"
+    #[serde(foo = "bar")]
+    pub struct Foo {}
+    "#,
+    ) {
+        Err(FromAttrsError::UnknownAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_container(
+        r#"
+    #[serde("foo")]
+    pub struct Foo {}
+    "#,
+    ) {
+        Err(FromAttrsError::NestedMetaTypeMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_container(
+        r#"
+    #[serde(rename = 1)]
+    pub struct Foo {}
+    "#,
+    ) {
+        Err(FromAttrsError::InvalidAttribute(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_container(
+        r#"
+    #[serde(default = "a::b", default)]
+    pub struct Foo {}
+    "#,
+    ) {
+        Err(FromAttrsError::DuplicateAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_container(
+        r#"
+    #[serde(tag = "a", tag = "b")]
+    pub enum Foo {}
+    "#,
+    ) {
+        Err(FromAttrsError::DuplicateAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_container(
+        r#"
+    #[serde(untagged)]
+    #[serde(tag = "t")]
+    pub enum Foo {}
+    "#,
+    ) {
+        Err(FromAttrsError::InvalidAttribute(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    for (input, expected) in [
+        (
+            r#"
+    #[serde(untagged)]
+    pub struct Foo {}
+    "#,
+            "#[serde(untagged)] can only be used on enums",
+        ),
+        (
+            r#"
+    #[serde(tag = "t", content = "c")]
+    pub struct Foo {}
+    "#,
+            r#"#[serde(content = "...")] can only be used on enums"#,
+        ),
+        (
+            r#"
+    #[serde(tag = "t")]
+    pub struct Foo(u8);
+    "#,
+            r#"#[serde(tag = "...")] can only be used on enums and structs with named fields"#,
+        ),
+        (
+            r#"
+    #[serde(rename_all_fields = "camelCase")]
+    pub struct Foo {}
+    "#,
+            "#[serde(rename_all_fields)] can only be used on enums",
+        ),
+        (
+            r#"
+    #[serde(tag = "t")]
+    pub enum Foo {
+        A(u8, u8),
+    }
+    "#,
+            r#"#[serde(tag = "...")] cannot be used with tuple variants"#,
+        ),
+    ] {
+        match parse_container(input) {
+            Err(FromAttrsError::InvalidAttribute(_, err)) => assert_eq!(err.to_string(), expected),
+            x => panic!("{:?}", x),
+        }
+    }
+    assert_eq!(
+        parse_container(
+            r#"
+    #[serde(tag = "t")]
+    pub struct Foo {}
+    "#
+        )
+        .unwrap()
+        .enum_repr,
+        EnumRepr::Internal {
+            tag: "t".to_owned()
+        }
+    );
+    assert_eq!(
+        parse_container(
+            r#"
+    #[serde(tag = "t", content = "c")]
+    pub enum Foo {
+        A(u8, u8),
+        B(u8),
+    }
+    "#
+        )
+        .unwrap()
+        .enum_repr,
+        EnumRepr::Adjacent {
+            tag: "t".to_owned(),
+            content: "c".to_owned()
+        }
+    );
+}
+
+#[test]
+fn into_error() {
+    for (input, expected) in [
+        (
+            r#"
+    #[serde(foo)]
+    pub struct Foo {}
+    "#,
+            "unknown serde attribute `foo`",
+        ),
+        (
+            r#"
+    #[serde("foo")]
+    pub struct Foo {}
+    "#,
+            "unexpected literal in serde attribute",
+        ),
+        (
+            r#"
+    #[serde(rename = 1)]
+    pub struct Foo {}
+    "#,
+            r#"expected serde rename attribute to be a string: `rename = "..."`"#,
+        ),
+        (
+            r#"
+    #[serde(default = "a::b", default)]
+    pub struct Foo {}
+    "#,
+            "duplicate serde attribute `default`",
+        ),
+        (
+            r#"
+    #[serde(transparent = "true")]
+    pub struct Foo {}
+    "#,
+            "malformed transparent attribute, expected `transparent`",
+        ),
+        (
+            r#"
+    #[serde(remote = "a::")]
+    pub struct Foo {}
+    "#,
+            r#"failed to parse path: "a::""#,
+        ),
+        (
+            r#"
+    #[serde(untagged, tag = "t")]
+    pub enum Foo {}
+    "#,
+            "enum cannot be both untagged and internally tagged",
+        ),
+    ] {
+        let err = Error::from(parse_container(input).unwrap_err());
+        assert_eq!(err.to_string(), expected);
+        assert_eq!(syn2::Error::from(err).to_string(), expected);
+    }
+}
+
+#[test]
+fn into_error_with_multiple() {
+    let input = r#"
+    #[serde(foo, rename = 1)]
+    #[serde(default, default, deny_unknown_fields = "true")]
+    pub struct Foo {}
+    "#;
+    match parse_container(input) {
+        Err(FromAttrsError::Multiple(errs)) => assert_eq!(errs.len(), 4),
+        x => panic!("{:?}", x),
+    }
+
+    let err = Error::from(parse_container(input).unwrap_err());
+    assert_eq!(
+        syn::Error::from(err)
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "unknown serde attribute `foo`",
+            r#"expected serde rename attribute to be a string: `rename = "..."`"#,
+            "duplicate serde attribute `default`",
+            "malformed deny_unknown_fields attribute, expected `deny_unknown_fields`",
+        ]
+    );
+}
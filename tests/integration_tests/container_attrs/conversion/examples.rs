@@ -0,0 +1,27 @@
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+#[serde(from = "u8", into = "u8")]
+pub struct FooA(pub u8);
+
+impl From<u8> for FooA {
+    fn from(v: u8) -> Self {
+        Self(v)
+    }
+}
+
+impl From<FooA> for u8 {
+    fn from(v: FooA) -> Self {
+        v.0
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(try_from = "String")]
+pub struct FooB(pub u8);
+
+impl std::convert::TryFrom<String> for FooB {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(v: String) -> Result<Self, Self::Error> {
+        v.parse().map(Self)
+    }
+}
@@ -0,0 +1,125 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use darling::FromMeta as _;
+use serde_attributes::{
+    attrs::FromAttrsError, conversion::syn::FromMetaError, Container, Conversion, ConversionFrom,
+};
+use syn::{parse_quote, parse_str, DeriveInput};
+
+use super::{parse_darling_conversion, parse_serde_meta_list};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=2].join("\r\n");
+    let conversion = Conversion::try_from_metas(&parse_serde_meta_list(&input))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        conversion,
+        Conversion {
+            from: Some(ConversionFrom::From(parse_quote!(u8))),
+            into: Some(parse_quote!(u8)),
+        }
+    );
+    assert_eq!(conversion.ser_type(), Some(&parse_quote!(u8)));
+    assert_eq!(conversion.de_type(), Some(&parse_quote!(u8)));
+    assert!(!conversion.is_fallible());
+    assert_eq!(parse_darling_conversion(&input).unwrap(), conversion);
+    assert_eq!(
+        Container::try_from(&parse_str::<DeriveInput>(&input).unwrap())
+            .unwrap()
+            .conversion,
+        Some(conversion)
+    );
+
+    let input = lines[16..=18].join("\r\n");
+    let conversion = Conversion::try_from(&parse_serde_meta_list(&input)[0]).unwrap();
+    assert_eq!(
+        conversion,
+        Conversion {
+            from: Some(ConversionFrom::TryFrom(parse_quote!(String))),
+            into: None,
+        }
+    );
+    assert_eq!(conversion.ser_type(), None);
+    assert_eq!(conversion.de_type(), Some(&parse_quote!(String)));
+    assert!(conversion.is_fallible());
+    assert_eq!(parse_darling_conversion(&input).unwrap(), conversion);
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(from = "u8", try_from = "String")]
+    pub struct Foo(pub u8);
+    "#;
+    match Conversion::try_from_metas(&parse_serde_meta_list(input)) {
+        Err(FromMetaError::FromWithTryFrom(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_conversion(input).is_err());
+    match Container::try_from(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(FromAttrsError::InvalidAttribute(_, err)) => assert_eq!(
+            err.to_string(),
+            r#"#[serde(from = "...")] and #[serde(try_from = "...")] conflict with each other"#
+        ),
+        x => panic!("{:?}", x),
+    }
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(into = "u8", into = "u16")]
+    pub struct Foo(pub u8);
+    "#;
+    match Conversion::try_from_metas(&parse_serde_meta_list(input)) {
+        Err(FromMetaError::DuplicateMeta(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_conversion(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(into = 1)]
+    pub struct Foo(pub u8);
+    "#;
+    match Conversion::try_from(&parse_serde_meta_list(input)[0]) {
+        Err(FromMetaError::LitTypeMismatch(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_conversion(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(from = "1 + 1")]
+    pub struct Foo(pub u8);
+    "#;
+    match Conversion::try_from(&parse_serde_meta_list(input)[0]) {
+        Err(FromMetaError::TypeParseFailed(_, _, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_conversion(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(from(into = "u8"))]
+    pub struct Foo(pub u8);
+    "#;
+    assert_eq!(
+        Conversion::from_meta(&parse_serde_meta_list(input)[0])
+            .unwrap_err()
+            .to_string(),
+        "Unknown field: `from`"
+    );
+    assert_eq!(
+        parse_darling_conversion(input).unwrap_err().to_string(),
+        "Unknown field: `from`"
+    );
+}
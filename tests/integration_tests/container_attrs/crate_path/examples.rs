@@ -0,0 +1,3 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(crate = "serde")]
+pub struct FooA(pub u8);
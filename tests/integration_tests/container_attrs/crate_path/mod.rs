@@ -0,0 +1,54 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{crate_path::syn::FromMetaError, Container, CratePath};
+use syn::{parse_quote, parse_str, DeriveInput, Path};
+
+use super::{parse_darling_crate_path, parse_serde_meta};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=2].join("\r\n");
+    let crate_path = CratePath::try_from(&parse_serde_meta(&input)).unwrap();
+    assert_eq!(crate_path, CratePath(parse_quote!(serde)));
+    assert_eq!(parse_darling_crate_path(&input).unwrap(), crate_path);
+    let container = Container::try_from(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+    assert_eq!(container.crate_path, Some(crate_path));
+    assert_eq!(
+        container.serde_path().as_ref(),
+        &parse_quote!(serde) as &Path
+    );
+
+    let container = Container::default();
+    assert_eq!(
+        container.serde_path().as_ref(),
+        &parse_quote!(::serde) as &Path
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(crate = "1 + 1")]
+    pub struct Foo;
+    "#;
+    match CratePath::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::PathParseFailed(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_crate_path(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(crate = "serde", crate = "serde")]
+    pub struct Foo;
+    "#;
+    assert!(Container::try_from(&parse_str::<DeriveInput>(input).unwrap()).is_err());
+}
@@ -0,0 +1,15 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Foo")]
+pub enum FooA {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "t")]
+pub enum FooB {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "t", content = "c")]
+pub enum FooC {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum FooD {}
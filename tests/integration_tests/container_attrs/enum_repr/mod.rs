@@ -0,0 +1,127 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{enum_repr::syn::FromMetaError, EnumRepr};
+
+use super::{parse_darling_enum_repr, parse_serde_meta_list};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=2].join("\r\n");
+    assert_eq!(
+        EnumRepr::try_from(&parse_serde_meta_list(&input)[..]).unwrap(),
+        EnumRepr::External
+    );
+    assert_eq!(parse_darling_enum_repr(&input).unwrap(), EnumRepr::External);
+
+    let input = lines[4..=6].join("\r\n");
+    assert_eq!(
+        EnumRepr::try_from(&parse_serde_meta_list(&input)[..]).unwrap(),
+        EnumRepr::Internal {
+            tag: "t".to_owned()
+        }
+    );
+    assert_eq!(
+        parse_darling_enum_repr(&input).unwrap(),
+        EnumRepr::Internal {
+            tag: "t".to_owned()
+        }
+    );
+
+    let input = lines[8..=10].join("\r\n");
+    assert_eq!(
+        EnumRepr::try_from(&parse_serde_meta_list(&input)[..]).unwrap(),
+        EnumRepr::Adjacent {
+            tag: "t".to_owned(),
+            content: "c".to_owned()
+        }
+    );
+    assert_eq!(
+        parse_darling_enum_repr(&input).unwrap(),
+        EnumRepr::Adjacent {
+            tag: "t".to_owned(),
+            content: "c".to_owned()
+        }
+    );
+
+    let input = lines[12..=14].join("\r\n");
+    assert_eq!(
+        EnumRepr::try_from(&parse_serde_meta_list(&input)[..]).unwrap(),
+        EnumRepr::Untagged
+    );
+    assert_eq!(parse_darling_enum_repr(&input).unwrap(), EnumRepr::Untagged);
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(content = "c")]
+    pub enum Foo {}
+    "#;
+    match EnumRepr::try_from(&parse_serde_meta_list(input)[..]) {
+        Err(FromMetaError::ContentWithoutTag(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_enum_repr(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(untagged, tag = "t")]
+    pub enum Foo {}
+    "#;
+    match EnumRepr::try_from(&parse_serde_meta_list(input)[..]) {
+        Err(FromMetaError::UntaggedWithTag(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert_eq!(
+        parse_darling_enum_repr(input).unwrap_err().to_string(),
+        "enum cannot be both untagged and internally tagged"
+    );
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(untagged, content = "c")]
+    pub enum Foo {}
+    "#;
+    match EnumRepr::try_from(&parse_serde_meta_list(input)[..]) {
+        Err(FromMetaError::UntaggedWithContent(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_enum_repr(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(untagged, tag = "t", content = "c")]
+    pub enum Foo {}
+    "#;
+    let metas = parse_serde_meta_list(input);
+    match EnumRepr::try_from(&metas[..]) {
+        Err(err @ FromMetaError::UntaggedWithTagAndContent(_, _, _)) => assert_eq!(
+            err.into_error().to_string(),
+            r#"untagged enum cannot have #[serde(tag = "...", content = "...")]"#
+        ),
+        x => panic!("{:?}", x),
+    }
+    assert_eq!(
+        parse_darling_enum_repr(input).unwrap_err().to_string(),
+        r#"untagged enum cannot have #[serde(tag = "...", content = "...")]"#
+    );
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(tag = 1)]
+    pub enum Foo {}
+    "#;
+    match EnumRepr::try_from(&parse_serde_meta_list(input)[..]) {
+        Err(FromMetaError::LitTypeMismatch(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_enum_repr(input).is_err());
+}
@@ -0,0 +1,3 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(expecting = "a pair of coordinates")]
+pub struct FooA(pub f64, pub f64);
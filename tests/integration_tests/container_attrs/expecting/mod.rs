@@ -0,0 +1,59 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{expecting::syn::FromMetaError, Container, Expecting};
+use syn::{parse_str, DeriveInput};
+
+use super::{parse_darling_expecting, parse_serde_meta};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=2].join("\r\n");
+    let expecting = Expecting::try_from(&parse_serde_meta(&input)).unwrap();
+    assert_eq!(expecting, Expecting("a pair of coordinates".to_owned()));
+    assert_eq!(parse_darling_expecting(&input).unwrap(), expecting);
+    assert_eq!(
+        Container::try_from(&parse_str::<DeriveInput>(&input).unwrap())
+            .unwrap()
+            .expecting,
+        Some(expecting)
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(expecting = 1)]
+    pub struct Foo(pub f64, pub f64);
+    "#;
+    match Expecting::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::LitTypeMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_expecting(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(expecting("a pair of coordinates"))]
+    pub struct Foo(pub f64, pub f64);
+    "#;
+    match Expecting::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::MetaTypeOrPathMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_expecting(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(expecting = "a pair", expecting = "coordinates")]
+    pub struct Foo(pub f64, pub f64);
+    "#;
+    assert!(Container::try_from(&parse_str::<DeriveInput>(input).unwrap()).is_err());
+}
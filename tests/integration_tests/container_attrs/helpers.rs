@@ -1,8 +1,17 @@
-use darling::{Error as DarlingError, FromDeriveInput};
-use serde_attributes::{Rename, RenameAll};
+use darling::{Error as DarlingError, FromDeriveInput, FromMeta as _};
+use darling_core020::{Error as Darling020Error, FromMeta as Darling020FromMeta};
+use serde_attributes::syn2_attrs;
+use serde_attributes::{
+    Bound, Conversion, CratePath, DenyUnknownFields, EnumRepr, Expecting, Identifier, Remote,
+    Rename, RenameAll, RenameAllFields, Transparent,
+};
 use syn::{parse_str, DeriveInput, Meta, MetaList, NestedMeta};
 
 pub fn parse_serde_meta(input: &str) -> Meta {
+    parse_serde_meta_list(input).first().cloned().unwrap()
+}
+
+pub fn parse_serde_meta_list(input: &str) -> Vec<Meta> {
     let derive_input = parse_str::<DeriveInput>(input).unwrap();
     let attrs = &derive_input.attrs;
     match attrs[0].parse_meta().unwrap() {
@@ -21,10 +30,13 @@ pub fn parse_serde_meta(input: &str) -> Meta {
             path,
             paren_token: _,
             nested,
-        }) if path.is_ident("serde") => match nested.first().cloned() {
-            Some(NestedMeta::Meta(meta)) => meta,
-            _ => panic!(),
-        },
+        }) if path.is_ident("serde") => nested
+            .into_iter()
+            .map(|nested_meta| match nested_meta {
+                NestedMeta::Meta(meta) => meta,
+                _ => panic!(),
+            })
+            .collect(),
         meta => {
             println!("{:?}", meta);
             panic!()
@@ -32,6 +44,27 @@ pub fn parse_serde_meta(input: &str) -> Meta {
     }
 }
 
+pub fn parse_syn2_serde_meta(input: &str) -> syn2::Meta {
+    let derive_input = syn2::parse_str::<syn2::DeriveInput>(input).unwrap();
+    syn2_attrs::parse_serde_meta_list(&derive_input.attrs)
+        .unwrap()
+        .first()
+        .cloned()
+        .unwrap()
+}
+
+pub fn parse_darling020_rename(input: &str) -> Result<Rename, Darling020Error> {
+    <Rename as Darling020FromMeta>::from_meta(&parse_syn2_serde_meta(input))
+}
+
+pub fn parse_darling020_rename_all(input: &str) -> Result<RenameAll, Darling020Error> {
+    <RenameAll as Darling020FromMeta>::from_meta(&parse_syn2_serde_meta(input))
+}
+
+pub fn parse_darling020_rename_all_fields(input: &str) -> Result<RenameAllFields, Darling020Error> {
+    <RenameAllFields as Darling020FromMeta>::from_meta(&parse_syn2_serde_meta(input))
+}
+
 pub fn parse_darling_rename(input: &str) -> Result<Rename, DarlingError> {
     #[derive(FromDeriveInput)]
     #[darling(attributes(serde))]
@@ -73,3 +106,139 @@ pub fn parse_darling_rename_all(input: &str) -> Result<RenameAll, DarlingError>
     )
     .map(|x| x.rename_all)
 }
+
+pub fn parse_darling_rename_all_fields(input: &str) -> Result<RenameAllFields, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        rename_all_fields: RenameAllFields,
+    }
+
+    SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )
+    .map(|x| x.rename_all_fields)
+}
+
+pub fn parse_darling_enum_repr(input: &str) -> Result<EnumRepr, DarlingError> {
+    let derive_input = parse_str::<DeriveInput>(input).unwrap();
+    EnumRepr::from_meta(&derive_input.attrs[1].parse_meta().unwrap())
+}
+
+pub fn parse_darling_flags(
+    input: &str,
+) -> Result<(Option<DenyUnknownFields>, Option<Transparent>), DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        #[darling(default)]
+        deny_unknown_fields: Option<DenyUnknownFields>,
+        #[darling(default)]
+        transparent: Option<Transparent>,
+    }
+
+    SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )
+    .map(|x| (x.deny_unknown_fields, x.transparent))
+}
+
+pub fn parse_darling_conversion(input: &str) -> Result<Conversion, DarlingError> {
+    let derive_input = parse_str::<DeriveInput>(input).unwrap();
+    Conversion::from_meta(&derive_input.attrs[1].parse_meta().unwrap())
+}
+
+pub fn parse_darling_bound(input: &str) -> Result<Bound, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        bound: Bound,
+    }
+
+    SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )
+    .map(|x| x.bound)
+}
+
+pub fn parse_darling_crate_path(input: &str) -> Result<CratePath, DarlingError> {
+    let derive_input = parse_str::<DeriveInput>(input).unwrap();
+    match derive_input.attrs[1].parse_meta().unwrap() {
+        Meta::List(MetaList { nested, .. }) => match nested.first() {
+            Some(NestedMeta::Meta(meta)) => CratePath::from_meta(meta),
+            nested_meta => panic!("{:?}", nested_meta),
+        },
+        meta => panic!("{:?}", meta),
+    }
+}
+
+pub fn parse_darling_remote(input: &str) -> Result<Remote, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        remote: Remote,
+    }
+
+    SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )
+    .map(|x| x.remote)
+}
+
+pub fn parse_darling_identifier(input: &str) -> Result<Identifier, DarlingError> {
+    let derive_input = parse_str::<DeriveInput>(input).unwrap();
+    Identifier::from_meta(&derive_input.attrs[1].parse_meta().unwrap())
+}
+
+pub fn parse_darling_expecting(input: &str) -> Result<Expecting, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        expecting: Expecting,
+    }
+
+    SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )
+    .map(|x| x.expecting)
+}
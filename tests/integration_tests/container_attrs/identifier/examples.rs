@@ -0,0 +1,32 @@
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+pub enum FooA {
+    Bar,
+    Baz,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(variant_identifier)]
+pub enum FooB {
+    Bar,
+    Baz,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type")]
+pub enum FooC {
+    Bar {
+        baz: u8,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(field_identifier)]
+pub enum FooD {
+    Bar,
+    Other(String),
+}
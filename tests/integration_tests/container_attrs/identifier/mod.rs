@@ -0,0 +1,193 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{
+    attrs::FromAttrsError,
+    identifier::syn::{check_identifier, FromMetaError},
+    Container, Identifier, Variant,
+};
+use syn::{parse_str, Data, DeriveInput};
+
+use super::{parse_darling_identifier, parse_serde_meta_list};
+
+fn parse_variants(input: &str) -> Vec<Variant> {
+    match parse_str::<DeriveInput>(input).unwrap().data {
+        Data::Enum(data_enum) => Variant::try_from_data_enum(&data_enum).unwrap(),
+        data => panic!("{:?}", data),
+    }
+}
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=7].join("\r\n");
+    assert_eq!(
+        Identifier::try_from(&parse_serde_meta_list(&input)[..]).unwrap(),
+        Identifier::Field
+    );
+    assert_eq!(parse_darling_identifier(&input).unwrap(), Identifier::Field);
+    assert_eq!(
+        Container::try_from(&parse_str::<DeriveInput>(&input).unwrap())
+            .unwrap()
+            .identifier,
+        Identifier::Field
+    );
+    assert_eq!(
+        parse_variants(&input)
+            .iter()
+            .map(|variant| variant.other.is_some())
+            .collect::<Vec<_>>(),
+        vec![false, false, true]
+    );
+    check_identifier(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+
+    let input = lines[9..=14].join("\r\n");
+    assert_eq!(
+        Identifier::try_from(&parse_serde_meta_list(&input)[..]).unwrap(),
+        Identifier::Variant
+    );
+    assert_eq!(
+        parse_darling_identifier(&input).unwrap(),
+        Identifier::Variant
+    );
+    check_identifier(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+
+    let input = lines[16..=24].join("\r\n");
+    assert_eq!(
+        Identifier::try_from(&parse_serde_meta_list(&input)[..]).unwrap(),
+        Identifier::No
+    );
+    check_identifier(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+
+    let input = lines[26..=31].join("\r\n");
+    check_identifier(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Deserialize)]
+    #[serde(field_identifier, variant_identifier)]
+    pub enum Foo {
+        Bar,
+    }
+    "#;
+    match Identifier::try_from(&parse_serde_meta_list(input)[..]) {
+        Err(FromMetaError::FieldWithVariant(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_identifier(input).is_err());
+    match Container::try_from(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(FromAttrsError::InvalidAttribute(_, err)) => assert_eq!(
+            err.to_string(),
+            "#[serde(field_identifier)] and #[serde(variant_identifier)] cannot both be set"
+        ),
+        x => panic!("{:?}", x),
+    }
+
+    for (input, expected) in &[
+        (
+            r#"
+    #[serde(field_identifier)]
+    pub struct Foo;
+    "#,
+            "NotAnEnum",
+        ),
+        (
+            r#"
+    #[serde(variant_identifier)]
+    pub enum Foo {
+        Bar,
+        #[serde(other)]
+        Baz,
+    }
+    "#,
+            "OtherInVariantIdentifier",
+        ),
+        (
+            r#"
+    #[serde(untagged)]
+    pub enum Foo {
+        Bar(u8),
+        #[serde(other)]
+        Baz,
+    }
+    "#,
+            "OtherInUntagged",
+        ),
+        (
+            r#"
+    pub enum Foo {
+        Bar(u8),
+        #[serde(other)]
+        Baz,
+    }
+    "#,
+            "OtherInExternallyTagged",
+        ),
+        (
+            r#"
+    #[serde(tag = "type")]
+    pub enum Foo {
+        #[serde(other)]
+        Bar,
+        Baz,
+    }
+    "#,
+            "OtherNotLast",
+        ),
+        (
+            r#"
+    #[serde(tag = "type")]
+    pub enum Foo {
+        Bar,
+        #[serde(other)]
+        Baz { qux: u8 },
+    }
+    "#,
+            "OtherNotUnit",
+        ),
+        (
+            r#"
+    #[serde(tag = "type")]
+    pub enum Foo {
+        #[serde(other)]
+        Bar,
+        #[serde(other)]
+        Baz,
+    }
+    "#,
+            "DuplicateOther",
+        ),
+        (
+            r#"
+    #[serde(field_identifier)]
+    pub enum Foo {
+        Bar(String),
+        Baz,
+    }
+    "#,
+            "CatchAllNotLast",
+        ),
+        (
+            r#"
+    #[serde(variant_identifier)]
+    pub enum Foo {
+        Bar,
+        Baz(String),
+    }
+    "#,
+            "NotUnitVariant",
+        ),
+    ] {
+        match check_identifier(&parse_str::<DeriveInput>(input).unwrap()) {
+            Err(err) => assert_eq!(&format!("{:?}", err), expected),
+            Ok(()) => panic!("{}", input),
+        }
+    }
+}
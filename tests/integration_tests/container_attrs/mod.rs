@@ -2,7 +2,27 @@ mod helpers;
 
 use helpers::*;
 
+#[cfg(test)]
+mod bound;
+#[cfg(test)]
+mod container;
+#[cfg(test)]
+mod conversion;
+#[cfg(test)]
+mod crate_path;
+#[cfg(test)]
+mod enum_repr;
+#[cfg(test)]
+mod expecting;
+#[cfg(test)]
+mod identifier;
+#[cfg(test)]
+mod remote;
 #[cfg(test)]
 mod rename;
 #[cfg(test)]
 mod rename_all;
+#[cfg(test)]
+mod rename_all_fields;
+#[cfg(test)]
+mod transparent;
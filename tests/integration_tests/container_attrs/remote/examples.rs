@@ -0,0 +1,17 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Duration")]
+pub struct DurationDef {
+    secs: i64,
+    nanos: i32,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Self")]
+pub struct FooB {
+    bar: u8,
+}
+
+pub struct Duration {
+    pub secs: i64,
+    pub nanos: i32,
+}
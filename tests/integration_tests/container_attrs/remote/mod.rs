@@ -0,0 +1,61 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{remote::syn::FromMetaError, Container, Remote};
+use syn::{parse_quote, parse_str, DeriveInput};
+
+use super::{parse_darling_remote, parse_serde_meta};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=5].join("\r\n");
+    let remote = Remote::try_from(&parse_serde_meta(&input)).unwrap();
+    assert_eq!(remote, Remote(parse_quote!(Duration)));
+    assert_eq!(parse_darling_remote(&input).unwrap(), remote);
+    let container = Container::try_from(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+    assert_eq!(container.remote, Some(remote));
+    assert!(container.emits_inherent_fns());
+
+    let input = lines[7..=11].join("\r\n");
+    let remote = Remote::try_from(&parse_serde_meta(&input)).unwrap();
+    assert_eq!(remote, Remote(parse_quote!(Self)));
+    assert_eq!(
+        Container::try_from(&parse_str::<DeriveInput>(&input).unwrap())
+            .unwrap()
+            .remote,
+        Some(Remote(parse_quote!(FooB)))
+    );
+
+    assert!(!Container::default().emits_inherent_fns());
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(remote = "1 + 1")]
+    pub struct Foo;
+    "#;
+    match Remote::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::PathParseFailed(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_remote(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(remote)]
+    pub struct Foo;
+    "#;
+    match Remote::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::MetaTypeOrPathMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_remote(input).is_err());
+}
@@ -5,9 +5,15 @@ mod examples;
 //
 use std::convert::TryFrom as _;
 
+use darling::FromMeta as _;
+use quote::ToTokens as _;
+use syn::{parse2, Meta};
+
 use serde_attributes::{Rename, RenameIndependent};
 
-use super::{parse_darling_rename, parse_serde_meta};
+use super::{
+    parse_darling020_rename, parse_darling_rename, parse_serde_meta, parse_syn2_serde_meta,
+};
 
 #[test]
 fn simple() {
@@ -18,30 +24,54 @@ fn simple() {
         Rename::try_from(&parse_serde_meta(&input)).unwrap(),
         Rename::Normal("name".to_owned())
     );
+    assert_eq!(
+        Rename::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        Rename::Normal("name".to_owned())
+    );
     assert_eq!(
         parse_darling_rename(&input).unwrap(),
         Rename::Normal("name".to_owned())
     );
+    assert_eq!(
+        parse_darling020_rename(&input).unwrap(),
+        Rename::Normal("name".to_owned())
+    );
 
     let input = lines[4..=6].join("\r\n");
     assert_eq!(
         Rename::try_from(&parse_serde_meta(&input)).unwrap(),
         Rename::Independent(RenameIndependent::Serialize("ser_name".to_owned()))
     );
+    assert_eq!(
+        Rename::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        Rename::Independent(RenameIndependent::Serialize("ser_name".to_owned()))
+    );
     assert_eq!(
         parse_darling_rename(&input).unwrap(),
         Rename::Independent(RenameIndependent::Serialize("ser_name".to_owned()))
     );
+    assert_eq!(
+        parse_darling020_rename(&input).unwrap(),
+        Rename::Independent(RenameIndependent::Serialize("ser_name".to_owned()))
+    );
 
     let input = lines[8..=10].join("\r\n");
     assert_eq!(
         Rename::try_from(&parse_serde_meta(&input)).unwrap(),
         Rename::Independent(RenameIndependent::Deserialize("de_name".to_owned()))
     );
+    assert_eq!(
+        Rename::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        Rename::Independent(RenameIndependent::Deserialize("de_name".to_owned()))
+    );
     assert_eq!(
         parse_darling_rename(&input).unwrap(),
         Rename::Independent(RenameIndependent::Deserialize("de_name".to_owned()))
     );
+    assert_eq!(
+        parse_darling020_rename(&input).unwrap(),
+        Rename::Independent(RenameIndependent::Deserialize("de_name".to_owned()))
+    );
 
     let input = lines[12..=14].join("\r\n");
     assert_eq!(
@@ -51,6 +81,13 @@ fn simple() {
             deserialize: "de_name".to_owned()
         })
     );
+    assert_eq!(
+        Rename::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        Rename::Independent(RenameIndependent::Both {
+            serialize: "ser_name".to_owned(),
+            deserialize: "de_name".to_owned()
+        })
+    );
     assert_eq!(
         parse_darling_rename(&input).unwrap(),
         Rename::Independent(RenameIndependent::Both {
@@ -58,4 +95,95 @@ fn simple() {
             deserialize: "de_name".to_owned()
         })
     );
+    assert_eq!(
+        parse_darling020_rename(&input).unwrap(),
+        Rename::Independent(RenameIndependent::Both {
+            serialize: "ser_name".to_owned(),
+            deserialize: "de_name".to_owned()
+        })
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = 1)]
+    pub struct Foo;
+    "#;
+    // darling 0.20 words `unexpected_type` as "Unexpected type".
+    assert_eq!(
+        parse_darling020_rename(input).unwrap_err().to_string(),
+        Rename::from_meta(&parse_serde_meta(input))
+            .unwrap_err()
+            .to_string()
+            .replace("literal type", "type")
+    );
+    let meta = parse_serde_meta(input);
+    let mut err = Rename::try_from(&meta).unwrap_err().into_error(&meta);
+    assert_eq!(
+        err.to_string(),
+        r#"expected serde rename attribute to be a string: `rename = "..."`"#
+    );
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename(ser = "name"))]
+    pub struct Foo;
+    "#;
+    assert_eq!(
+        parse_darling020_rename(input).unwrap_err().to_string(),
+        Rename::from_meta(&parse_serde_meta(input))
+            .unwrap_err()
+            .to_string()
+    );
+    let meta = parse_serde_meta(input);
+    err.combine(Rename::try_from(&meta).unwrap_err().into_error(&meta));
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename())]
+    pub struct Foo;
+    "#;
+    assert_eq!(
+        parse_darling020_rename(input).unwrap_err().to_string(),
+        Rename::from_meta(&parse_serde_meta(input))
+            .unwrap_err()
+            .to_string()
+    );
+    let meta = parse_serde_meta(input);
+    err.combine(Rename::try_from(&meta).unwrap_err().into_error(&meta));
+
+    assert_eq!(
+        syn::Error::from(err)
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            r#"expected serde rename attribute to be a string: `rename = "..."`"#,
+            "malformed rename attribute, expected `rename(serialize = ..., deserialize = ...)`",
+            "malformed rename attribute, expected at least one of `serialize` and `deserialize`",
+        ]
+    );
+}
+
+#[test]
+fn to_tokens() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    for input in lines.chunks(4).map(|x| x[0..=2].join("\r\n")) {
+        let rename = Rename::try_from(&parse_serde_meta(&input)).unwrap();
+        let meta: Meta = parse2(rename.to_token_stream()).unwrap();
+        assert_eq!(Rename::try_from(&meta).unwrap(), rename);
+    }
+}
+
+#[test]
+fn to_meta() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    for input in lines.chunks(4).map(|x| x[0..=2].join("\r\n")) {
+        let meta = parse_serde_meta(&input);
+        assert_eq!(Meta::from(&Rename::try_from(&meta).unwrap()), meta);
+    }
 }
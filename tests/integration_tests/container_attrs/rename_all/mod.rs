@@ -5,11 +5,17 @@ mod examples;
 //
 use std::convert::TryFrom as _;
 
+use darling::FromMeta as _;
+use quote::ToTokens as _;
+use syn::{parse2, Meta};
+
 use serde_attributes::{
     rename_all::serde_rename_rule::RenameRule, RenameAll, RenameAllIndependent,
 };
 
-use super::{parse_darling_rename_all, parse_serde_meta};
+use super::{
+    parse_darling020_rename_all, parse_darling_rename_all, parse_serde_meta, parse_syn2_serde_meta,
+};
 
 #[test]
 fn simple() {
@@ -20,30 +26,54 @@ fn simple() {
         RenameAll::try_from(&parse_serde_meta(&input)).unwrap(),
         RenameAll::Normal(RenameRule::SnakeCase)
     );
+    assert_eq!(
+        RenameAll::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        RenameAll::Normal(RenameRule::SnakeCase)
+    );
     assert_eq!(
         parse_darling_rename_all(&input).unwrap(),
         RenameAll::Normal(RenameRule::SnakeCase)
     );
+    assert_eq!(
+        parse_darling020_rename_all(&input).unwrap(),
+        RenameAll::Normal(RenameRule::SnakeCase)
+    );
 
     let input = lines[4..=6].join("\r\n");
     assert_eq!(
         RenameAll::try_from(&parse_serde_meta(&input)).unwrap(),
         RenameAll::Independent(RenameAllIndependent::Serialize(RenameRule::LowerCase))
     );
+    assert_eq!(
+        RenameAll::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        RenameAll::Independent(RenameAllIndependent::Serialize(RenameRule::LowerCase))
+    );
     assert_eq!(
         parse_darling_rename_all(&input).unwrap(),
         RenameAll::Independent(RenameAllIndependent::Serialize(RenameRule::LowerCase))
     );
+    assert_eq!(
+        parse_darling020_rename_all(&input).unwrap(),
+        RenameAll::Independent(RenameAllIndependent::Serialize(RenameRule::LowerCase))
+    );
 
     let input = lines[8..=10].join("\r\n");
     assert_eq!(
         RenameAll::try_from(&parse_serde_meta(&input)).unwrap(),
         RenameAll::Independent(RenameAllIndependent::Deserialize(RenameRule::UpperCase))
     );
+    assert_eq!(
+        RenameAll::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        RenameAll::Independent(RenameAllIndependent::Deserialize(RenameRule::UpperCase))
+    );
     assert_eq!(
         parse_darling_rename_all(&input).unwrap(),
         RenameAll::Independent(RenameAllIndependent::Deserialize(RenameRule::UpperCase))
     );
+    assert_eq!(
+        parse_darling020_rename_all(&input).unwrap(),
+        RenameAll::Independent(RenameAllIndependent::Deserialize(RenameRule::UpperCase))
+    );
 
     let input = lines[12..=14].join("\r\n");
     assert_eq!(
@@ -53,6 +83,13 @@ fn simple() {
             deserialize: RenameRule::UpperCase
         })
     );
+    assert_eq!(
+        RenameAll::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        RenameAll::Independent(RenameAllIndependent::Both {
+            serialize: RenameRule::LowerCase,
+            deserialize: RenameRule::UpperCase
+        })
+    );
     assert_eq!(
         parse_darling_rename_all(&input).unwrap(),
         RenameAll::Independent(RenameAllIndependent::Both {
@@ -60,4 +97,77 @@ fn simple() {
             deserialize: RenameRule::UpperCase
         })
     );
+    assert_eq!(
+        parse_darling020_rename_all(&input).unwrap(),
+        RenameAll::Independent(RenameAllIndependent::Both {
+            serialize: RenameRule::LowerCase,
+            deserialize: RenameRule::UpperCase
+        })
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "foo")]
+    pub struct Foo;
+    "#;
+    assert_eq!(
+        parse_darling020_rename_all(input).unwrap_err().to_string(),
+        RenameAll::from_meta(&parse_serde_meta(input))
+            .unwrap_err()
+            .to_string()
+    );
+    let meta = parse_serde_meta(input);
+    assert_eq!(
+        RenameAll::try_from(&meta)
+            .unwrap_err()
+            .into_error(&meta)
+            .to_string(),
+        r#"unknown rename rule `rename_all = "foo"`, expected one of "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE", "kebab-case", "SCREAMING-KEBAB-CASE""#
+    );
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all(serialize = 1))]
+    pub struct Foo;
+    "#;
+    // darling 0.20 words `unexpected_type` as "Unexpected type".
+    assert_eq!(
+        parse_darling020_rename_all(input).unwrap_err().to_string(),
+        RenameAll::from_meta(&parse_serde_meta(input))
+            .unwrap_err()
+            .to_string()
+            .replace("literal type", "type")
+    );
+    let meta = parse_serde_meta(input);
+    assert_eq!(
+        RenameAll::try_from(&meta)
+            .unwrap_err()
+            .into_error(&meta)
+            .to_string(),
+        r#"expected serde rename_all attribute to be a string: `rename_all = "..."`"#
+    );
+}
+
+#[test]
+fn to_tokens() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    for input in lines.chunks(4).map(|x| x[0..=2].join("\r\n")) {
+        let rename_all = RenameAll::try_from(&parse_serde_meta(&input)).unwrap();
+        let meta: Meta = parse2(rename_all.to_token_stream()).unwrap();
+        assert_eq!(RenameAll::try_from(&meta).unwrap(), rename_all);
+    }
+}
+
+#[test]
+fn to_meta() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    for input in lines.chunks(4).map(|x| x[0..=2].join("\r\n")) {
+        let meta = parse_serde_meta(&input);
+        assert_eq!(Meta::from(&RenameAll::try_from(&meta).unwrap()), meta);
+    }
 }
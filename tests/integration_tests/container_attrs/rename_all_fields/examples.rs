@@ -0,0 +1,15 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all_fields = "camelCase")]
+pub enum FooA {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all_fields(serialize = "lowercase"))]
+pub enum FooB {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all_fields(deserialize = "UPPERCASE"))]
+pub enum FooC {}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all_fields(serialize = "lowercase", deserialize = "UPPERCASE"))]
+pub enum FooD {}
@@ -0,0 +1,186 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use darling::FromMeta as _;
+use quote::ToTokens as _;
+use syn::{parse2, Meta};
+
+use serde_attributes::{
+    rename_all::serde_rename_rule::RenameRule, RenameAllFields, RenameAllFieldsIndependent,
+};
+
+use super::{
+    parse_darling020_rename_all_fields, parse_darling_rename_all_fields, parse_serde_meta,
+    parse_syn2_serde_meta,
+};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=2].join("\r\n");
+    assert_eq!(
+        RenameAllFields::try_from(&parse_serde_meta(&input)).unwrap(),
+        RenameAllFields::Normal(RenameRule::CamelCase)
+    );
+    assert_eq!(
+        RenameAllFields::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        RenameAllFields::Normal(RenameRule::CamelCase)
+    );
+    assert_eq!(
+        parse_darling_rename_all_fields(&input).unwrap(),
+        RenameAllFields::Normal(RenameRule::CamelCase)
+    );
+    assert_eq!(
+        parse_darling020_rename_all_fields(&input).unwrap(),
+        RenameAllFields::Normal(RenameRule::CamelCase)
+    );
+
+    let input = lines[4..=6].join("\r\n");
+    assert_eq!(
+        RenameAllFields::try_from(&parse_serde_meta(&input)).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Serialize(RenameRule::LowerCase))
+    );
+    assert_eq!(
+        RenameAllFields::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Serialize(RenameRule::LowerCase))
+    );
+    assert_eq!(
+        parse_darling_rename_all_fields(&input).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Serialize(RenameRule::LowerCase))
+    );
+    assert_eq!(
+        parse_darling020_rename_all_fields(&input).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Serialize(RenameRule::LowerCase))
+    );
+
+    let input = lines[8..=10].join("\r\n");
+    assert_eq!(
+        RenameAllFields::try_from(&parse_serde_meta(&input)).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Deserialize(
+            RenameRule::UpperCase
+        ))
+    );
+    assert_eq!(
+        RenameAllFields::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Deserialize(
+            RenameRule::UpperCase
+        ))
+    );
+    assert_eq!(
+        parse_darling_rename_all_fields(&input).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Deserialize(
+            RenameRule::UpperCase
+        ))
+    );
+    assert_eq!(
+        parse_darling020_rename_all_fields(&input).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Deserialize(
+            RenameRule::UpperCase
+        ))
+    );
+
+    let input = lines[12..=14].join("\r\n");
+    assert_eq!(
+        RenameAllFields::try_from(&parse_serde_meta(&input)).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Both {
+            serialize: RenameRule::LowerCase,
+            deserialize: RenameRule::UpperCase
+        })
+    );
+    assert_eq!(
+        RenameAllFields::try_from(&parse_syn2_serde_meta(&input)).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Both {
+            serialize: RenameRule::LowerCase,
+            deserialize: RenameRule::UpperCase
+        })
+    );
+    assert_eq!(
+        parse_darling_rename_all_fields(&input).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Both {
+            serialize: RenameRule::LowerCase,
+            deserialize: RenameRule::UpperCase
+        })
+    );
+    assert_eq!(
+        parse_darling020_rename_all_fields(&input).unwrap(),
+        RenameAllFields::Independent(RenameAllFieldsIndependent::Both {
+            serialize: RenameRule::LowerCase,
+            deserialize: RenameRule::UpperCase
+        })
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all_fields = "foo")]
+    pub enum Foo {}
+    "#;
+    assert_eq!(
+        parse_darling020_rename_all_fields(input)
+            .unwrap_err()
+            .to_string(),
+        RenameAllFields::from_meta(&parse_serde_meta(input))
+            .unwrap_err()
+            .to_string()
+    );
+    let meta = parse_serde_meta(input);
+    assert_eq!(
+        RenameAllFields::try_from(&meta)
+            .unwrap_err()
+            .into_error(&meta)
+            .to_string(),
+        r#"unknown rename rule `rename_all = "foo"`, expected one of "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE", "kebab-case", "SCREAMING-KEBAB-CASE""#
+    );
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all_fields(serialize = 1))]
+    pub enum Foo {}
+    "#;
+    // darling 0.20 words `unexpected_type` as "Unexpected type".
+    assert_eq!(
+        parse_darling020_rename_all_fields(input)
+            .unwrap_err()
+            .to_string(),
+        RenameAllFields::from_meta(&parse_serde_meta(input))
+            .unwrap_err()
+            .to_string()
+            .replace("literal type", "type")
+    );
+    let meta = parse_serde_meta(input);
+    assert_eq!(
+        RenameAllFields::try_from(&meta)
+            .unwrap_err()
+            .into_error(&meta)
+            .to_string(),
+        r#"expected serde rename_all_fields attribute to be a string: `rename_all_fields = "..."`"#
+    );
+}
+
+#[test]
+fn to_tokens() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    for input in lines.chunks(4).map(|x| x[0..=2].join("\r\n")) {
+        let rename_all_fields = RenameAllFields::try_from(&parse_serde_meta(&input)).unwrap();
+        let meta: Meta = parse2(rename_all_fields.to_token_stream()).unwrap();
+        assert_eq!(RenameAllFields::try_from(&meta).unwrap(), rename_all_fields);
+    }
+}
+
+#[test]
+fn to_meta() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    for input in lines.chunks(4).map(|x| x[0..=2].join("\r\n")) {
+        let meta = parse_serde_meta(&input);
+        assert_eq!(Meta::from(&RenameAllFields::try_from(&meta).unwrap()), meta);
+    }
+}
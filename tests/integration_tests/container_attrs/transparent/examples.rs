@@ -0,0 +1,40 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct FooA(pub usize);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct FooB {
+    #[serde(skip)]
+    pub bar: usize,
+    pub baz: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FooC {
+    pub bar: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct FooD<T> {
+    pub inner: u8,
+    pub marker: std::marker::PhantomData<T>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(transparent)]
+pub struct FooE {
+    pub a: u8,
+    #[serde(default)]
+    pub b: u8,
+}
+
+#[derive(serde::Serialize)]
+#[serde(transparent)]
+pub struct FooF {
+    pub a: u8,
+    #[serde(skip_serializing)]
+    pub b: u8,
+}
@@ -0,0 +1,171 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{
+    deny_unknown_fields::syn::FromMetaError as DenyUnknownFieldsFromMetaError,
+    transparent::syn::{
+        check_transparent, CheckTransparentError, FromMetaError, TransparentFields,
+    },
+    DenyUnknownFields, Transparent,
+};
+use syn::{parse_str, DeriveInput};
+
+use super::{parse_darling_flags, parse_serde_meta};
+
+fn check(input: &str) -> Result<Option<TransparentFields>, CheckTransparentError> {
+    check_transparent(&parse_str::<DeriveInput>(input).unwrap())
+}
+
+fn check_both(input: &str) -> (usize, usize) {
+    let fields = check(input).unwrap().unwrap();
+    (fields.serialize.unwrap(), fields.deserialize.unwrap())
+}
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=2].join("\r\n");
+    assert_eq!(
+        Transparent::try_from(&parse_serde_meta(&input)).unwrap(),
+        Transparent
+    );
+    assert_eq!(
+        parse_darling_flags(&input).unwrap(),
+        (None, Some(Transparent))
+    );
+    assert_eq!(check_both(&input), (0, 0));
+
+    let input = lines[4..=10].join("\r\n");
+    assert_eq!(check_both(&input), (1, 1));
+
+    let input = lines[12..=16].join("\r\n");
+    assert_eq!(
+        DenyUnknownFields::try_from(&parse_serde_meta(&input)).unwrap(),
+        DenyUnknownFields
+    );
+    assert_eq!(
+        parse_darling_flags(&input).unwrap(),
+        (Some(DenyUnknownFields), None)
+    );
+    assert!(check(&input).unwrap().is_none());
+
+    let input = lines[18..=23].join("\r\n");
+    assert_eq!(check_both(&input), (0, 0));
+
+    let input = lines[25..=31].join("\r\n");
+    let fields = check(&input).unwrap().unwrap();
+    match fields.serialize {
+        Err(CheckTransparentError::MoreThanOneField(_, 0, 1)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert_eq!(fields.deserialize.unwrap(), 0);
+
+    let input = lines[33..=39].join("\r\n");
+    let fields = check(&input).unwrap().unwrap();
+    assert_eq!(fields.serialize.unwrap(), 0);
+    match fields.deserialize {
+        Err(CheckTransparentError::MoreThanOneField(_, 0, 1)) => {}
+        x => panic!("{:?}", x),
+    }
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(transparent = "true")]
+    pub struct Foo(pub usize);
+    "#;
+    match Transparent::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::MetaTypeOrPathMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_flags(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(deny_unknown_fields(true))]
+    pub struct Foo(pub usize);
+    "#;
+    match DenyUnknownFields::try_from(&parse_serde_meta(input)) {
+        Err(DenyUnknownFieldsFromMetaError::MetaTypeOrPathMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_flags(input).is_err());
+
+    match check(
+        r#"
+    #[serde(transparent)]
+    pub enum Foo {
+        Bar(usize),
+    }
+    "#,
+    ) {
+        Err(CheckTransparentError::NotAStruct(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match check(
+        r#"
+    #[serde(transparent)]
+    pub struct Foo;
+    "#,
+    ) {
+        Err(CheckTransparentError::UnitStruct(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    let fields = check(
+        r#"
+    #[serde(transparent)]
+    pub struct Foo {
+        #[serde(skip_serializing)]
+        pub bar: usize,
+    }
+    "#,
+    )
+    .unwrap()
+    .unwrap();
+    match fields.serialize {
+        Err(CheckTransparentError::NoSerializeField(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert_eq!(fields.deserialize.unwrap(), 0);
+
+    let fields = check(
+        r#"
+    #[serde(transparent)]
+    pub struct Foo {
+        #[serde(default)]
+        pub bar: usize,
+    }
+    "#,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(fields.serialize.unwrap(), 0);
+    match fields.deserialize {
+        Err(CheckTransparentError::NoDeserializeField(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    let fields = check(
+        r#"
+    #[serde(transparent)]
+    pub struct Foo(pub usize, #[serde(skip)] pub usize, pub String);
+    "#,
+    )
+    .unwrap()
+    .unwrap();
+    for result in [fields.serialize, fields.deserialize] {
+        match result {
+            Err(CheckTransparentError::MoreThanOneField(_, 0, 2)) => {}
+            x => panic!("{:?}", x),
+        }
+    }
+}
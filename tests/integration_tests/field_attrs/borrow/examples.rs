@@ -0,0 +1,17 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooA<'a> {
+    #[serde(borrow)]
+    pub bar: std::borrow::Cow<'a, str>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooB<'a, 'b> {
+    #[serde(borrow = "'a + 'b")]
+    pub bar: (std::borrow::Cow<'a, str>, std::borrow::Cow<'b, str>),
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum FooC<'a> {
+    #[serde(borrow)]
+    Bar(std::borrow::Cow<'a, str>),
+}
@@ -0,0 +1,123 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{
+    borrow::syn::{borrowable_lifetimes, check_borrow, CheckBorrowError, FromMetaError},
+    Borrow, Variant,
+};
+use syn::{parse_quote, parse_str, Data, DeriveInput, Lifetime};
+
+use super::{parse_darling_borrow, parse_serde_meta};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=4].join("\r\n");
+    let borrow = Borrow::try_from(&parse_serde_meta(&input)).unwrap();
+    assert_eq!(borrow, Borrow::All);
+    assert_eq!(borrow.lifetimes(), None);
+    assert_eq!(parse_darling_borrow(&input).unwrap(), Some(borrow));
+    check_borrow(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+
+    let input = lines[6..=10].join("\r\n");
+    let borrow = Borrow::try_from(&parse_serde_meta(&input)).unwrap();
+    let lifetimes: Vec<Lifetime> = vec![parse_quote!('a), parse_quote!('b)];
+    assert_eq!(borrow, Borrow::Lifetimes(lifetimes.into_iter().collect()));
+    assert_eq!(parse_darling_borrow(&input).unwrap(), Some(borrow));
+    check_borrow(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+
+    let input = lines[12..=16].join("\r\n");
+    let derive_input = parse_str::<DeriveInput>(&input).unwrap();
+    match &derive_input.data {
+        Data::Enum(data_enum) => assert_eq!(
+            Variant::try_from(&data_enum.variants[0]).unwrap().borrow,
+            Some(Borrow::All)
+        ),
+        data => panic!("{:?}", data),
+    }
+    check_borrow(&derive_input).unwrap();
+}
+
+#[test]
+fn test_borrowable_lifetimes() {
+    let lifetimes: Vec<Lifetime> = vec![parse_quote!('a), parse_quote!('b)];
+    assert_eq!(
+        borrowable_lifetimes(&parse_quote!(Option<(&'a str, Vec<Cow<'b, [u8]>>)>)),
+        lifetimes.into_iter().collect()
+    );
+    assert!(borrowable_lifetimes(&parse_quote!(for<'a> fn(&'a str))).is_empty());
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo<'a> {
+        #[serde(borrow = "'a + 'a")]
+        pub bar: std::borrow::Cow<'a, str>,
+    }
+    "#;
+    match Borrow::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::LifetimesParseFailed(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_borrow(input).is_err());
+    match check_borrow(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(CheckBorrowError::FromAttrsFailed(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(borrow)]
+        pub bar: String,
+    }
+    "#;
+    match check_borrow(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(CheckBorrowError::NoLifetimes(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo<'a> {
+        #[serde(borrow = "'b")]
+        pub bar: std::borrow::Cow<'a, str>,
+    }
+    "#;
+    match check_borrow(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(CheckBorrowError::UndeclaredLifetime(lifetime)) => assert_eq!(lifetime.ident, "b"),
+        x => panic!("{:?}", x),
+    }
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo<'a, 'b> {
+        #[serde(borrow = "'b")]
+        pub bar: std::borrow::Cow<'a, str>,
+        pub baz: std::borrow::Cow<'b, str>,
+    }
+    "#;
+    match check_borrow(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(CheckBorrowError::LifetimeNotInField(lifetime)) => assert_eq!(lifetime.ident, "b"),
+        x => panic!("{:?}", x),
+    }
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub enum Foo<'a> {
+        #[serde(borrow)]
+        Bar(std::borrow::Cow<'a, str>, std::borrow::Cow<'a, str>),
+    }
+    "#;
+    match check_borrow(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(CheckBorrowError::NotNewtypeVariant(_)) => {}
+        x => panic!("{:?}", x),
+    }
+}
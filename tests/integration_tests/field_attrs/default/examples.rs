@@ -0,0 +1,15 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooA {
+    #[serde(default)]
+    pub bar: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooB {
+    #[serde(default = "default_bar")]
+    pub bar: usize,
+}
+
+pub fn default_bar() -> usize {
+    1
+}
@@ -0,0 +1,78 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::default::{syn::FromMetaError, Default as DefaultAttr};
+use syn::parse_quote;
+
+use super::{parse_darling_default, parse_serde_meta};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=4].join("\r\n");
+    assert_eq!(
+        DefaultAttr::try_from(&parse_serde_meta(&input)).unwrap(),
+        DefaultAttr::Default
+    );
+    assert_eq!(
+        parse_darling_default(&input).unwrap(),
+        Some(DefaultAttr::Default)
+    );
+
+    let input = lines[6..=10].join("\r\n");
+    assert_eq!(
+        DefaultAttr::try_from(&parse_serde_meta(&input)).unwrap(),
+        DefaultAttr::Path(parse_quote!(default_bar))
+    );
+    assert_eq!(
+        parse_darling_default(&input).unwrap(),
+        Some(DefaultAttr::Path(parse_quote!(default_bar)))
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(default = 1)]
+        pub bar: usize,
+    }
+    "#;
+    match DefaultAttr::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::LitTypeMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_default(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(default = "1 + 1")]
+        pub bar: usize,
+    }
+    "#;
+    match DefaultAttr::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::ExprPathParseFailed(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_default(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(default("default_bar"))]
+        pub bar: usize,
+    }
+    "#;
+    match DefaultAttr::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::MetaTypeOrPathMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_default(input).is_err());
+}
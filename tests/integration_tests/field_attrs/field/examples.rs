@@ -0,0 +1,36 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooA {
+    #[serde(rename = "name", alias = "name_a", alias = "name_b", default)]
+    pub bar: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooB {
+    #[serde(default = "default_bar", skip_serializing_if = "Option::is_none")]
+    #[serde(with = "bar")]
+    pub bar: Option<usize>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooC<'a> {
+    #[serde(borrow = "'a", bound(deserialize = "'de: 'a"))]
+    pub bar: std::borrow::Cow<'a, str>,
+    #[serde(skip)]
+    pub baz: usize,
+    #[serde(flatten)]
+    pub qux: std::collections::HashMap<String, String>,
+}
+
+pub fn default_bar() -> Option<usize> {
+    None
+}
+
+pub mod bar {
+    pub fn serialize<S: serde::Serializer>(v: &Option<usize>, s: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(v, s)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Option<usize>, D::Error> {
+        serde::Deserialize::deserialize(d)
+    }
+}
@@ -0,0 +1,143 @@
+mod examples;
+
+//
+//
+//
+use std::{collections::BTreeSet, convert::TryFrom as _};
+
+use serde_attributes::{
+    attrs::FromAttrsError, default::Default as DefaultAttr, Alias, Borrow, Bound, BoundIndependent,
+    Field, Flatten, Rename, Skip, With,
+};
+use syn::{parse_quote, parse_str, Data, DeriveInput};
+
+fn parse_fields(input: &str) -> Result<Vec<Field>, FromAttrsError> {
+    match parse_str::<DeriveInput>(input).unwrap().data {
+        Data::Struct(data_struct) => data_struct.fields.iter().map(Field::try_from).collect(),
+        data => panic!("{:?}", data),
+    }
+}
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=4].join("\r\n");
+    assert_eq!(
+        parse_fields(&input).unwrap(),
+        vec![Field {
+            rename: Some(Rename::Normal("name".to_owned())),
+            alias: vec![Alias("name_a".to_owned()), Alias("name_b".to_owned())],
+            default: Some(DefaultAttr::Default),
+            ..Field::default()
+        }]
+    );
+
+    let input = lines[6..=11].join("\r\n");
+    assert_eq!(
+        parse_fields(&input).unwrap(),
+        vec![Field {
+            default: Some(DefaultAttr::Path(parse_quote!(default_bar))),
+            skip: Some(Skip {
+                serializing_if: Some(parse_quote!(Option::is_none)),
+                ..Skip::default()
+            }),
+            with: Some(With::Module(parse_quote!(bar))),
+            ..Field::default()
+        }]
+    );
+
+    let input = lines[13..=21].join("\r\n");
+    assert_eq!(
+        parse_fields(&input).unwrap(),
+        vec![
+            Field {
+                borrow: Some(Borrow::Lifetimes(
+                    vec![parse_quote!('a)].into_iter().collect::<BTreeSet<_>>()
+                )),
+                bound: Some(Bound::Independent(BoundIndependent::Deserialize(vec![
+                    parse_quote!('de: 'a)
+                ]))),
+                ..Field::default()
+            },
+            Field {
+                skip: Some(Skip {
+                    serializing: true,
+                    deserializing: true,
+                    ..Skip::default()
+                }),
+                ..Field::default()
+            },
+            Field {
+                flatten: Some(Flatten),
+                ..Field::default()
+            }
+        ]
+    );
+}
+
+#[test]
+fn with_invalid() {
+    match parse_fields(
+        r#"
+    pub struct Foo {
+        #[serde(skip, skip_serializing)]
+        pub bar: usize,
+    }
+    "#,
+    ) {
+        Err(FromAttrsError::DuplicateAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_fields(
+        r#"
+    pub struct Foo {
+        #[serde(with = "bar", serialize_with = "baz")]
+        pub bar: usize,
+    }
+    "#,
+    ) {
+        Err(FromAttrsError::InvalidAttribute(_, err)) => assert_eq!(
+            err.to_string(),
+            "duplicate serde attribute `serialize_with`"
+        ),
+        x => panic!("{:?}", x),
+    }
+
+    match parse_fields(
+        r#"
+    pub struct Foo {
+        #[serde(default = "1 + 1")]
+        pub bar: usize,
+    }
+    "#,
+    ) {
+        Err(FromAttrsError::InvalidAttribute(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_fields(
+        r#"
+    pub struct Foo {
+        #[serde(flatten = "true")]
+        pub bar: usize,
+    }
+    "#,
+    ) {
+        Err(FromAttrsError::InvalidAttribute(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_fields(
+        r#"
+    pub struct Foo {
+        #[serde(deny_unknown_fields)]
+        pub bar: usize,
+    }
+    "#,
+    ) {
+        Err(FromAttrsError::UnknownAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+}
@@ -0,0 +1,13 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooA {
+    #[serde(flatten)]
+    pub bar: std::collections::HashMap<String, String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum FooB {
+    Bar {
+        #[serde(flatten)]
+        baz: std::collections::HashMap<String, String>,
+    },
+}
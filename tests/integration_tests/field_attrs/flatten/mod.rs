@@ -0,0 +1,172 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{
+    flatten::syn::{check_flatten, CheckFlattenError, FromMetaError},
+    Error, Flatten,
+};
+use syn::{parse_str, DeriveInput};
+
+use super::{parse_darling_flatten, parse_serde_meta};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=4].join("\r\n");
+    assert_eq!(
+        Flatten::try_from(&parse_serde_meta(&input)).unwrap(),
+        Flatten
+    );
+    assert_eq!(parse_darling_flatten(&input).unwrap(), Some(Flatten));
+    check_flatten(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+
+    let input = lines[6..=12].join("\r\n");
+    check_flatten(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(flatten = "true")]
+        pub bar: usize,
+    }
+    "#;
+    match Flatten::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::MetaTypeOrPathMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_flatten(input).is_err());
+
+    match check_flatten(
+        &parse_str::<DeriveInput>(
+            r#"
+    pub struct Foo(#[serde(flatten)] std::collections::HashMap<String, String>);
+    "#,
+        )
+        .unwrap(),
+    ) {
+        Err(CheckFlattenError::InTuple { .. }) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match check_flatten(
+        &parse_str::<DeriveInput>(
+            r#"
+    pub enum Foo {
+        Bar(usize, #[serde(flatten)] std::collections::HashMap<String, String>),
+    }
+    "#,
+        )
+        .unwrap(),
+    ) {
+        Err(CheckFlattenError::InTuple { .. }) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match check_flatten(
+        &parse_str::<DeriveInput>(
+            r#"
+    pub struct Foo {
+        #[serde(flatten)]
+        #[serde(skip_deserializing)]
+        pub bar: std::collections::HashMap<String, String>,
+    }
+    "#,
+        )
+        .unwrap(),
+    ) {
+        Err(CheckFlattenError::WithSkip { .. }) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match check_flatten(
+        &parse_str::<DeriveInput>(
+            r#"
+    pub struct Foo {
+        #[serde(flatten, skip_serializing_if = "std::collections::HashMap::is_empty")]
+        pub bar: std::collections::HashMap<String, String>,
+    }
+    "#,
+        )
+        .unwrap(),
+    ) {
+        Err(err @ CheckFlattenError::WithSkipSerializingIf { .. }) => assert_eq!(
+            err.to_string(),
+            r#"#[serde(flatten)] can not be combined with #[serde(skip_serializing_if = "...")]"#
+        ),
+        x => panic!("{:?}", x),
+    }
+
+    match check_flatten(
+        &parse_str::<DeriveInput>(
+            r#"
+    #[serde(deny_unknown_fields)]
+    pub struct Foo {
+        pub bar: usize,
+        #[serde(flatten)]
+        pub baz: std::collections::HashMap<String, String>,
+    }
+    "#,
+        )
+        .unwrap(),
+    ) {
+        Err(CheckFlattenError::WithDenyUnknownFields { .. }) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match check_flatten(
+        &parse_str::<DeriveInput>(
+            r#"
+    pub struct Foo {
+        #[serde = "flatten"]
+        pub bar: usize,
+    }
+    "#,
+        )
+        .unwrap(),
+    ) {
+        Err(CheckFlattenError::FromAttrsFailed(_)) => {}
+        x => panic!("{:?}", x),
+    }
+}
+
+#[test]
+fn into_error() {
+    for (input, expected) in [
+        (
+            r#"
+    pub struct Foo(#[serde(flatten)] usize);
+    "#,
+            "#[serde(flatten)] cannot be used on tuple fields",
+        ),
+        (
+            r#"
+    pub struct Foo {
+        #[serde(flatten, skip)]
+        pub bar: usize,
+    }
+    "#,
+            "#[serde(flatten)] cannot be combined with #[serde(skip_*)]",
+        ),
+        (
+            r#"
+    pub struct Foo {
+        #[serde = "flatten"]
+        pub bar: usize,
+    }
+    "#,
+            "expected #[serde(...)]",
+        ),
+    ] {
+        let err =
+            Error::from(check_flatten(&parse_str::<DeriveInput>(input).unwrap()).unwrap_err());
+        assert_eq!(err.to_string(), expected);
+    }
+}
@@ -0,0 +1,32 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(remote = "Duration")]
+pub struct DurationDef {
+    #[serde(getter = "Duration::secs")]
+    secs: i64,
+    #[serde(getter = "Duration::nanos")]
+    nanos: i32,
+}
+
+pub struct Duration {
+    secs: i64,
+    nanos: i32,
+}
+
+impl Duration {
+    pub fn secs(&self) -> i64 {
+        self.secs
+    }
+
+    pub fn nanos(&self) -> i32 {
+        self.nanos
+    }
+}
+
+impl From<DurationDef> for Duration {
+    fn from(def: DurationDef) -> Self {
+        Self {
+            secs: def.secs,
+            nanos: def.nanos,
+        }
+    }
+}
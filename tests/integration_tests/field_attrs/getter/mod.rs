@@ -0,0 +1,69 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{
+    getter::syn::{check_getter, CheckGetterError, FromMetaError},
+    Getter,
+};
+use syn::{parse_quote, parse_str, DeriveInput};
+
+use super::{parse_darling_getter, parse_serde_meta};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=7].join("\r\n");
+    let getter = Getter::try_from(&parse_serde_meta(&input)).unwrap();
+    assert_eq!(getter, Getter(parse_quote!(Duration::secs)));
+    assert_eq!(parse_darling_getter(&input).unwrap(), Some(getter));
+    check_getter(&parse_str::<DeriveInput>(&input).unwrap()).unwrap();
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(remote = "Duration")]
+    pub struct DurationDef {
+        #[serde(getter = "1 + 1")]
+        secs: i64,
+    }
+    "#;
+    match Getter::try_from(&parse_serde_meta(input)) {
+        Err(FromMetaError::ExprPathParseFailed(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_getter(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct DurationDef {
+        #[serde(getter = "Duration::secs")]
+        secs: i64,
+    }
+    "#;
+    match check_getter(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(CheckGetterError::WithoutRemote(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(remote = "Foo")]
+    pub enum FooDef {
+        Bar {
+            #[serde(getter = "Foo::bar")]
+            bar: i64,
+        },
+    }
+    "#;
+    match check_getter(&parse_str::<DeriveInput>(input).unwrap()) {
+        Err(CheckGetterError::NotAStruct(_)) => {}
+        x => panic!("{:?}", x),
+    }
+}
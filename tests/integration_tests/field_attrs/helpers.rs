@@ -0,0 +1,303 @@
+use darling::{
+    ast::Data as DarlingData, util::Ignored, Error as DarlingError, FromDeriveInput, FromField,
+};
+use serde_attributes::{
+    default::Default as DefaultAttr, Borrow, Flatten, Getter, Remote, Skip, With,
+};
+use syn::{parse_str, Data, DataStruct, DeriveInput, Meta, MetaList, NestedMeta};
+
+pub fn parse_serde_meta(input: &str) -> Meta {
+    parse_serde_meta_list(input).first().cloned().unwrap()
+}
+
+pub fn parse_serde_meta_list(input: &str) -> Vec<Meta> {
+    let derive_input = parse_str::<DeriveInput>(input).unwrap();
+    let attrs = &derive_input.attrs;
+    match attrs[0].parse_meta().unwrap() {
+        Meta::List(MetaList {
+            path,
+            paren_token: _,
+            nested: _,
+        }) if path.is_ident("derive") => {}
+        meta => {
+            println!("{:?}", meta);
+            panic!()
+        }
+    }
+    match derive_input.data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            let field = fields.iter().next().unwrap();
+            field
+                .attrs
+                .iter()
+                .flat_map(|attr| match attr.parse_meta().unwrap() {
+                    Meta::List(MetaList {
+                        path,
+                        paren_token: _,
+                        nested,
+                    }) if path.is_ident("serde") => nested.into_iter(),
+                    meta => {
+                        println!("{:?}", meta);
+                        panic!()
+                    }
+                })
+                .map(|nested_meta| match nested_meta {
+                    NestedMeta::Meta(meta) => meta,
+                    _ => panic!(),
+                })
+                .collect()
+        }
+        data => {
+            println!("{:?}", data);
+            panic!()
+        }
+    }
+}
+
+pub fn parse_darling_default(input: &str) -> Result<Option<DefaultAttr>, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        data: DarlingData<Ignored, SerdeField>,
+    }
+
+    #[derive(FromField, Debug, Clone)]
+    #[darling(attributes(serde))]
+    struct SerdeField {
+        #[darling(default)]
+        default: Option<DefaultAttr>,
+    }
+
+    let derive = SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )?;
+
+    let fields = match derive.data {
+        DarlingData::Struct(fields) => fields,
+        data => {
+            println!("{:?}", data);
+            panic!()
+        }
+    };
+
+    Ok(fields.fields[0].to_owned().default)
+}
+
+pub fn parse_darling_skip(input: &str) -> Result<Option<Skip>, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        data: DarlingData<Ignored, SerdeField>,
+    }
+
+    #[derive(FromField, Debug, Clone)]
+    #[darling(attributes(serde))]
+    struct SerdeField {
+        #[darling(default)]
+        skip: Option<Skip>,
+        #[darling(default)]
+        skip_serializing: Option<Skip>,
+        #[darling(default)]
+        skip_deserializing: Option<Skip>,
+        #[darling(default)]
+        skip_serializing_if: Option<Skip>,
+    }
+
+    let derive = SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )?;
+
+    let fields = match derive.data {
+        DarlingData::Struct(fields) => fields,
+        data => {
+            println!("{:?}", data);
+            panic!()
+        }
+    };
+
+    let field = fields.fields[0].to_owned();
+    Ok(vec![
+        field.skip,
+        field.skip_serializing,
+        field.skip_deserializing,
+        field.skip_serializing_if,
+    ]
+    .into_iter()
+    .flatten()
+    .fold(None, |prev: Option<Skip>, skip| match prev {
+        Some(prev) => prev.merge(skip),
+        None => Some(skip),
+    }))
+}
+
+pub fn parse_darling_with(input: &str) -> Result<Option<With>, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        data: DarlingData<Ignored, SerdeField>,
+    }
+
+    #[derive(FromField, Debug, Clone)]
+    #[darling(attributes(serde))]
+    struct SerdeField {
+        #[darling(default)]
+        with: Option<With>,
+        #[darling(default)]
+        serialize_with: Option<With>,
+        #[darling(default)]
+        deserialize_with: Option<With>,
+    }
+
+    let derive = SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )?;
+
+    let fields = match derive.data {
+        DarlingData::Struct(fields) => fields,
+        data => {
+            println!("{:?}", data);
+            panic!()
+        }
+    };
+
+    let field = fields.fields[0].to_owned();
+    With::from_darling_fields(field.with, field.serialize_with, field.deserialize_with)
+}
+
+pub fn parse_darling_flatten(input: &str) -> Result<Option<Flatten>, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        data: DarlingData<Ignored, SerdeField>,
+    }
+
+    #[derive(FromField, Debug, Clone)]
+    #[darling(attributes(serde))]
+    struct SerdeField {
+        #[darling(default)]
+        flatten: Option<Flatten>,
+    }
+
+    let derive = SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )?;
+
+    let fields = match derive.data {
+        DarlingData::Struct(fields) => fields,
+        data => {
+            println!("{:?}", data);
+            panic!()
+        }
+    };
+
+    Ok(fields.fields[0].to_owned().flatten)
+}
+
+pub fn parse_darling_borrow(input: &str) -> Result<Option<Borrow>, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        data: DarlingData<Ignored, SerdeField>,
+    }
+
+    #[derive(FromField, Debug, Clone)]
+    #[darling(attributes(serde))]
+    struct SerdeField {
+        #[darling(default)]
+        borrow: Option<Borrow>,
+    }
+
+    let derive = SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )?;
+
+    let fields = match derive.data {
+        DarlingData::Struct(fields) => fields,
+        data => {
+            println!("{:?}", data);
+            panic!()
+        }
+    };
+
+    Ok(fields.fields[0].to_owned().borrow)
+}
+
+pub fn parse_darling_getter(input: &str) -> Result<Option<Getter>, DarlingError> {
+    #[derive(FromDeriveInput)]
+    #[darling(attributes(serde))]
+    struct SerdeDerive {
+        #[darling(default)]
+        #[allow(dead_code)]
+        remote: Option<Remote>,
+        data: DarlingData<Ignored, SerdeField>,
+    }
+
+    #[derive(FromField, Debug, Clone)]
+    #[darling(attributes(serde))]
+    struct SerdeField {
+        #[darling(default)]
+        getter: Option<Getter>,
+    }
+
+    let derive = SerdeDerive::from_derive_input(
+        &parse_str(
+            input
+                .replace(
+                    r#"#[derive(serde::Serialize, serde::Deserialize)]"#,
+                    r#"#[derive(SerdeDerive)]"#,
+                )
+                .as_str(),
+        )
+        .unwrap(),
+    )?;
+
+    let fields = match derive.data {
+        DarlingData::Struct(fields) => fields,
+        data => {
+            println!("{:?}", data);
+            panic!()
+        }
+    };
+
+    Ok(fields.fields[0].to_owned().getter)
+}
@@ -1 +1,18 @@
+mod helpers;
 
+use helpers::*;
+
+#[cfg(test)]
+mod borrow;
+#[cfg(test)]
+mod default;
+#[cfg(test)]
+mod field;
+#[cfg(test)]
+mod flatten;
+#[cfg(test)]
+mod getter;
+#[cfg(test)]
+mod skip;
+#[cfg(test)]
+mod with;
@@ -0,0 +1,17 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooA {
+    #[serde(skip)]
+    pub bar: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooB {
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub bar: Option<usize>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum FooC {
+    #[serde(skip_serializing)]
+    Bar,
+}
@@ -0,0 +1,119 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{skip::syn::FromMetaError, Skip, Variant};
+use syn::{parse_quote, parse_str, Data, DeriveInput};
+
+use super::{parse_darling_skip, parse_serde_meta_list};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=4].join("\r\n");
+    assert_eq!(
+        Skip::try_from(&parse_serde_meta_list(&input)[0]).unwrap(),
+        Skip {
+            serializing: true,
+            deserializing: true,
+            ..Skip::default()
+        }
+    );
+    assert_eq!(
+        parse_darling_skip(&input).unwrap(),
+        Some(Skip {
+            serializing: true,
+            deserializing: true,
+            ..Skip::default()
+        })
+    );
+
+    let input = lines[6..=10].join("\r\n");
+    let skip = parse_serde_meta_list(&input)
+        .iter()
+        .map(|meta| Skip::try_from(meta).unwrap())
+        .try_fold(Skip::default(), Skip::merge)
+        .unwrap();
+    assert_eq!(
+        skip,
+        Skip {
+            deserializing: true,
+            serializing_if: Some(parse_quote!(Option::is_none)),
+            ..Skip::default()
+        }
+    );
+    assert!(!skip.is_skipped_on_ser());
+    assert!(skip.is_skipped_on_de());
+    assert_eq!(parse_darling_skip(&input).unwrap(), Some(skip));
+
+    let input = lines[12..=16].join("\r\n");
+    let variant = match parse_str::<DeriveInput>(&input).unwrap().data {
+        Data::Enum(data_enum) => Variant::try_from(&data_enum.variants[0]).unwrap(),
+        data => panic!("{:?}", data),
+    };
+    assert_eq!(
+        variant.skip,
+        Some(Skip {
+            serializing: true,
+            ..Skip::default()
+        })
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(skip = "true")]
+        pub bar: usize,
+    }
+    "#;
+    match Skip::try_from(&parse_serde_meta_list(input)[0]) {
+        Err(FromMetaError::MetaTypeOrPathMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_skip(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(skip_serializing_if = 1)]
+        pub bar: usize,
+    }
+    "#;
+    match Skip::try_from(&parse_serde_meta_list(input)[0]) {
+        Err(FromMetaError::LitTypeMismatch(_)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_skip(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(skip_serializing_if = "1 + 1")]
+        pub bar: usize,
+    }
+    "#;
+    match Skip::try_from(&parse_serde_meta_list(input)[0]) {
+        Err(FromMetaError::ExprPathParseFailed(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_skip(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub enum Foo {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Bar,
+    }
+    "#;
+    match parse_str::<DeriveInput>(input).unwrap().data {
+        Data::Enum(data_enum) => assert!(Variant::try_from(&data_enum.variants[0]).is_err()),
+        data => panic!("{:?}", data),
+    }
+}
@@ -0,0 +1,32 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooA {
+    #[serde(with = "bar")]
+    pub bar: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FooB {
+    #[serde(serialize_with = "bar::serialize")]
+    #[serde(deserialize_with = "bar::deserialize")]
+    pub bar: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum FooC {
+    #[serde(serialize_with = "baz")]
+    Bar(usize),
+}
+
+pub mod bar {
+    pub fn serialize<S: serde::Serializer>(v: &usize, s: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(v, s)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(d: D) -> Result<usize, D::Error> {
+        serde::Deserialize::deserialize(d)
+    }
+}
+
+pub fn baz<S: serde::Serializer>(v: &usize, s: S) -> Result<S::Ok, S::Error> {
+    serde::Serialize::serialize(v, s)
+}
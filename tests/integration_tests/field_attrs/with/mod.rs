@@ -0,0 +1,140 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use darling::FromMeta as _;
+use serde_attributes::{
+    attrs::FromAttrsError, with::syn::FromMetaError, Field, Variant, With, WithIndependent,
+};
+use syn::{parse_quote, parse_str, Data, DeriveInput};
+
+use super::{parse_darling_with, parse_serde_meta_list};
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=4].join("\r\n");
+    let with = With::try_from_metas(&parse_serde_meta_list(&input))
+        .unwrap()
+        .unwrap();
+    assert_eq!(with, With::Module(parse_quote!(bar)));
+    assert_eq!(with.ser_fn(), Some(parse_quote!(bar::serialize)));
+    assert_eq!(with.de_fn(), Some(parse_quote!(bar::deserialize)));
+    assert_eq!(
+        With::try_from(&parse_serde_meta_list(&input)[0]).unwrap(),
+        with
+    );
+    assert_eq!(parse_darling_with(&input).unwrap(), Some(with));
+
+    let input = lines[6..=11].join("\r\n");
+    let with = With::try_from_metas(&parse_serde_meta_list(&input))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        with,
+        With::Independent(WithIndependent::Both {
+            serialize: parse_quote!(bar::serialize),
+            deserialize: parse_quote!(bar::deserialize),
+        })
+    );
+    assert_eq!(with.ser_fn(), Some(parse_quote!(bar::serialize)));
+    assert_eq!(with.de_fn(), Some(parse_quote!(bar::deserialize)));
+    assert_eq!(parse_darling_with(&input).unwrap(), Some(with));
+
+    let input = lines[13..=17].join("\r\n");
+    let variant = match parse_str::<DeriveInput>(&input).unwrap().data {
+        Data::Enum(data_enum) => Variant::try_from(&data_enum.variants[0]).unwrap(),
+        data => panic!("{:?}", data),
+    };
+    assert_eq!(
+        variant.with,
+        Some(With::Independent(WithIndependent::Serialize(parse_quote!(
+            baz
+        ))))
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(with = "bar", serialize_with = "baz")]
+        pub bar: usize,
+    }
+    "#;
+    match With::try_from_metas(&parse_serde_meta_list(input)) {
+        Err(FromMetaError::ModuleConflict(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert_eq!(
+        parse_darling_with(input).unwrap_err().to_string(),
+        "with cannot be used together with serialize_with or deserialize_with"
+    );
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(with(serialize_with = "a"))]
+        pub bar: usize,
+    }
+    "#;
+    assert_eq!(
+        With::from_meta(&parse_serde_meta_list(input)[0])
+            .unwrap_err()
+            .to_string(),
+        "Unknown field: `with`"
+    );
+    assert!(parse_darling_with(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(deserialize_with = "bar")]
+        #[serde(deserialize_with = "baz")]
+        pub bar: usize,
+    }
+    "#;
+    match With::try_from_metas(&parse_serde_meta_list(input)) {
+        Err(FromMetaError::DuplicateMeta(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(with = 1)]
+        pub bar: usize,
+    }
+    "#;
+    match With::try_from(&parse_serde_meta_list(input)[0]) {
+        Err(FromMetaError::LitTypeMismatch(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    assert!(parse_darling_with(input).is_err());
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub struct Foo {
+        #[serde(serialize_with = "1 + 1")]
+        pub bar: usize,
+    }
+    "#;
+    match With::try_from(&parse_serde_meta_list(input)[0]) {
+        Err(FromMetaError::ExprPathParseFailed(_, _, _)) => {}
+        x => panic!("{:?}", x),
+    }
+    match parse_str::<DeriveInput>(input).unwrap().data {
+        Data::Struct(data_struct) => {
+            match Field::try_from(data_struct.fields.iter().next().unwrap()) {
+                Err(FromAttrsError::InvalidAttribute(_, _)) => {}
+                x => panic!("{:?}", x),
+            }
+        }
+        data => panic!("{:?}", data),
+    }
+}
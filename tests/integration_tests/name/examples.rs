@@ -0,0 +1,25 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "kebab-case"))]
+pub struct FooA {
+    pub foo_bar: usize,
+    #[serde(rename(serialize = "ser_name"), alias = "b", alias = "a")]
+    pub r#type: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(
+    rename = "Foo",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+pub enum FooB {
+    #[serde(rename_all = "UPPERCASE")]
+    BarBaz {
+        qux_quux: usize,
+    },
+    #[serde(rename = "renamed", alias = "bar")]
+    Qux(usize, usize),
+    Quux {
+        corge_grault: usize,
+    },
+}
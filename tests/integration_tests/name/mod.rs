@@ -0,0 +1,105 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{Container, Field, Name, Variant};
+use syn::{parse_str, Data, DeriveInput, Member};
+
+fn name(serialize: &str, deserialize: &str, deserialize_aliases: &[&str]) -> Name {
+    Name {
+        serialize: serialize.to_owned(),
+        deserialize: deserialize.to_owned(),
+        deserialize_aliases: deserialize_aliases.iter().map(|x| x.to_string()).collect(),
+    }
+}
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=6].join("\r\n");
+    let derive_input = parse_str::<DeriveInput>(&input).unwrap();
+    let container = Container::try_from(&derive_input).unwrap();
+    assert_eq!(
+        container.name(&derive_input.ident),
+        name("FooA", "FooA", &["FooA"])
+    );
+    let fields = match &derive_input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        data => panic!("{:?}", data),
+    };
+    let names: Vec<_> = fields
+        .iter()
+        .map(|field| {
+            let member = Member::Named(field.ident.clone().unwrap());
+            Field::try_from(field).unwrap().name(&member, &container)
+        })
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            name("fooBar", "foo-bar", &["foo-bar"]),
+            name("ser_name", "type", &["a", "b", "type"]),
+        ]
+    );
+
+    let input = lines[8..=24].join("\r\n");
+    let derive_input = parse_str::<DeriveInput>(&input).unwrap();
+    let container = Container::try_from(&derive_input).unwrap();
+    assert_eq!(
+        container.name(&derive_input.ident),
+        name("Foo", "Foo", &["Foo"])
+    );
+    let variants = match &derive_input.data {
+        Data::Enum(data_enum) => &data_enum.variants,
+        data => panic!("{:?}", data),
+    };
+
+    let variant = Variant::try_from(&variants[0]).unwrap();
+    assert_eq!(
+        variant.name(&variants[0].ident, &container),
+        name("bar_baz", "bar_baz", &["bar_baz"])
+    );
+    let field = &variants[0].fields.iter().next().unwrap();
+    assert_eq!(
+        Field::try_from(*field).unwrap().name_in_variant(
+            &Member::Named(field.ident.clone().unwrap()),
+            &variant,
+            &container
+        ),
+        name("QUX_QUUX", "QUX_QUUX", &["QUX_QUUX"])
+    );
+
+    let variant = Variant::try_from(&variants[1]).unwrap();
+    assert_eq!(
+        variant.name(&variants[1].ident, &container),
+        name("renamed", "renamed", &["bar", "renamed"])
+    );
+    let field = &variants[1].fields.iter().nth(1).unwrap();
+    assert_eq!(
+        Field::try_from(*field).unwrap().name_in_variant(
+            &Member::Unnamed(1.into()),
+            &variant,
+            &container
+        ),
+        name("1", "1", &["1"])
+    );
+
+    let variant = Variant::try_from(&variants[2]).unwrap();
+    assert_eq!(
+        variant.name(&variants[2].ident, &container),
+        name("quux", "quux", &["quux"])
+    );
+    let field = &variants[2].fields.iter().next().unwrap();
+    assert_eq!(
+        Field::try_from(*field).unwrap().name_in_variant(
+            &Member::Named(field.ident.clone().unwrap()),
+            &variant,
+            &container
+        ),
+        name("corgeGrault", "corgeGrault", &["corgeGrault"])
+    );
+}
@@ -5,9 +5,15 @@ mod examples;
 //
 use std::convert::TryFrom as _;
 
+use darling::FromMeta as _;
+use quote::ToTokens as _;
+use syn::{parse2, Meta};
+
 use serde_attributes::Alias;
 
-use super::{parse_darling_alias, parse_serde_meta_list};
+use super::{
+    parse_darling020_alias, parse_darling_alias, parse_serde_meta_list, parse_syn2_serde_meta_list,
+};
 
 #[test]
 fn simple() {
@@ -21,10 +27,21 @@ fn simple() {
             .collect::<Vec<_>>(),
         vec![Alias("name".to_owned())]
     );
+    assert_eq!(
+        parse_syn2_serde_meta_list(&input)
+            .iter()
+            .map(|x| Alias::try_from(x).unwrap())
+            .collect::<Vec<_>>(),
+        vec![Alias("name".to_owned())]
+    );
     assert_eq!(
         parse_darling_alias(&input).unwrap(),
         vec![Alias("name".to_owned())]
     );
+    assert_eq!(
+        parse_darling020_alias(&input).unwrap(),
+        vec![Alias("name".to_owned())]
+    );
 
     let input = lines[6..=11].join("\r\n");
     assert_eq!(
@@ -34,8 +51,94 @@ fn simple() {
             .collect::<Vec<_>>(),
         vec![Alias("name_a".to_owned()), Alias("name_b".to_owned())]
     );
+    assert_eq!(
+        parse_syn2_serde_meta_list(&input)
+            .iter()
+            .map(|x| Alias::try_from(x).unwrap())
+            .collect::<Vec<_>>(),
+        vec![Alias("name_a".to_owned()), Alias("name_b".to_owned())]
+    );
     assert_eq!(
         parse_darling_alias(&input).unwrap(),
         vec![Alias("name_a".to_owned()), Alias("name_b".to_owned())]
     );
+    assert_eq!(
+        parse_darling020_alias(&input).unwrap(),
+        vec![Alias("name_a".to_owned()), Alias("name_b".to_owned())]
+    );
+}
+
+#[test]
+fn with_invalid() {
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub enum Foo {
+        #[serde(alias = 1)]
+        Bar,
+    }
+    "#;
+    // darling 0.20 words `unexpected_type` as "Unexpected type".
+    assert_eq!(
+        parse_darling020_alias(input).unwrap_err().to_string(),
+        Alias::from_meta(&parse_serde_meta_list(input)[0])
+            .unwrap_err()
+            .to_string()
+            .replace("literal type", "type")
+    );
+    let meta = parse_serde_meta_list(input).remove(0);
+    assert_eq!(
+        Alias::try_from(&meta)
+            .unwrap_err()
+            .into_error(&meta)
+            .to_string(),
+        r#"expected serde alias attribute to be a string: `alias = "..."`"#
+    );
+
+    let input = r#"
+    #[derive(serde::Serialize, serde::Deserialize)]
+    pub enum Foo {
+        #[serde(alias)]
+        Bar,
+    }
+    "#;
+    // darling 0.20 words `unexpected_type` as "Unexpected type".
+    assert_eq!(
+        parse_darling020_alias(input).unwrap_err().to_string(),
+        Alias::from_meta(&parse_serde_meta_list(input)[0])
+            .unwrap_err()
+            .to_string()
+            .replace("literal type", "type")
+    );
+    let meta = parse_serde_meta_list(input).remove(0);
+    assert_eq!(
+        Alias::try_from(&meta)
+            .unwrap_err()
+            .into_error(&meta)
+            .to_string(),
+        r#"malformed alias attribute, expected `alias = "..."`"#
+    );
+}
+
+#[test]
+fn to_tokens() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[6..=11].join("\r\n");
+    for alias in parse_serde_meta_list(&input)
+        .iter()
+        .map(|x| Alias::try_from(x).unwrap())
+    {
+        let meta: Meta = parse2(alias.to_token_stream()).unwrap();
+        assert_eq!(Alias::try_from(&meta).unwrap(), alias);
+    }
+}
+
+#[test]
+fn to_meta() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[6..=11].join("\r\n");
+    for meta in parse_serde_meta_list(&input) {
+        assert_eq!(Meta::from(&Alias::try_from(&meta).unwrap()), meta);
+    }
 }
@@ -1,7 +1,8 @@
 use darling::{
     ast::Data as DarlingData, util::Ignored, Error as DarlingError, FromDeriveInput, FromVariant,
 };
-use serde_attributes::Alias;
+use darling_core020::{Error as Darling020Error, FromMeta as _};
+use serde_attributes::{syn2_attrs, Alias};
 use syn::{parse_str, Data, DataEnum, DeriveInput, Meta, MetaList, NestedMeta};
 
 #[allow(dead_code)]
@@ -55,6 +56,26 @@ pub fn parse_serde_meta_list(input: &str) -> Vec<Meta> {
     }
 }
 
+pub fn parse_syn2_serde_meta_list(input: &str) -> Vec<syn2::Meta> {
+    let derive_input = syn2::parse_str::<syn2::DeriveInput>(input).unwrap();
+    match derive_input.data {
+        syn2::Data::Enum(data_enum) => {
+            syn2_attrs::parse_serde_meta_list(&data_enum.variants[0].attrs).unwrap()
+        }
+        data => {
+            println!("{:?}", data);
+            panic!()
+        }
+    }
+}
+
+pub fn parse_darling020_alias(input: &str) -> Result<Vec<Alias>, Darling020Error> {
+    parse_syn2_serde_meta_list(input)
+        .iter()
+        .map(Alias::from_meta)
+        .collect()
+}
+
 pub fn parse_darling_alias(input: &str) -> Result<Vec<Alias>, DarlingError> {
     #[derive(FromDeriveInput)]
     #[darling(attributes(serde))]
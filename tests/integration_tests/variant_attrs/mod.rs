@@ -4,3 +4,5 @@ use helpers::*;
 
 #[cfg(test)]
 mod alias;
+#[cfg(test)]
+mod variant;
@@ -0,0 +1,20 @@
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum FooA {
+    #[serde(rename = "name", alias = "name_a")]
+    #[serde(alias = "name_b", rename_all = "camelCase")]
+    Bar { bar_baz: usize },
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum FooB {
+    #[serde(rename(serialize = "ser_name"))]
+    #[serde(rename(deserialize = "de_name"), skip_serializing)]
+    Bar,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum FooC {
+    Bar,
+    #[serde(untagged)]
+    Baz(usize),
+}
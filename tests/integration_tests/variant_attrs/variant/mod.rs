@@ -0,0 +1,173 @@
+mod examples;
+
+//
+//
+//
+use std::convert::TryFrom as _;
+
+use serde_attributes::{
+    attrs::FromAttrsError, rename_all::serde_rename_rule::RenameRule,
+    variant::syn::FromDataEnumError, Alias, Error, Rename, RenameAll, RenameIndependent, Skip,
+    Untagged, Variant,
+};
+use syn::{parse_str, Data, DeriveInput};
+
+fn parse_variant(input: &str) -> Result<Variant, FromAttrsError> {
+    match parse_str::<DeriveInput>(input).unwrap().data {
+        Data::Enum(data_enum) => Variant::try_from(&data_enum.variants[0]),
+        data => panic!("{:?}", data),
+    }
+}
+
+fn parse_variants(input: &str) -> Result<Vec<Variant>, FromDataEnumError> {
+    match parse_str::<DeriveInput>(input).unwrap().data {
+        Data::Enum(data_enum) => Variant::try_from_data_enum(&data_enum),
+        data => panic!("{:?}", data),
+    }
+}
+
+#[test]
+fn simple() {
+    let lines: Vec<_> = include_str!("examples.rs").lines().collect();
+
+    let input = lines[0..=5].join("\r\n");
+    assert_eq!(
+        parse_variant(&input).unwrap(),
+        Variant {
+            rename: Some(Rename::Normal("name".to_owned())),
+            alias: vec![Alias("name_a".to_owned()), Alias("name_b".to_owned())],
+            rename_all: Some(RenameAll::Normal(RenameRule::CamelCase)),
+            ..Variant::default()
+        }
+    );
+
+    let input = lines[7..=12].join("\r\n");
+    assert_eq!(
+        parse_variant(&input).unwrap(),
+        Variant {
+            rename: Some(Rename::Independent(RenameIndependent::Both {
+                serialize: "ser_name".to_owned(),
+                deserialize: "de_name".to_owned()
+            })),
+            skip: Some(Skip {
+                serializing: true,
+                ..Skip::default()
+            }),
+            ..Variant::default()
+        }
+    );
+
+    let input = lines[14..=19].join("\r\n");
+    assert_eq!(
+        parse_variants(&input).unwrap(),
+        vec![
+            Variant::default(),
+            Variant {
+                untagged: Some(Untagged),
+                ..Variant::default()
+            }
+        ]
+    );
+}
+
+#[test]
+fn with_invalid() {
+    match parse_variant(
+        r#"
+    pub enum Foo {
+        #[serde(rename = "a", rename = "b")]
+        Bar,
+    }
+    "#,
+    ) {
+        Err(FromAttrsError::DuplicateAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_variant(
+        r#"
+    pub enum Foo {
+        #[serde(alias = 1)]
+        Bar,
+    }
+    "#,
+    ) {
+        Err(FromAttrsError::InvalidAttribute(_, _)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_variant(
+        r#"
+    pub enum Foo {
+        #[serde(deny_unknown_fields)]
+        Bar,
+    }
+    "#,
+    ) {
+        Err(FromAttrsError::UnknownAttribute(_)) => {}
+        x => panic!("{:?}", x),
+    }
+
+    match parse_variants(
+        r#"
+    pub enum Foo {
+        #[serde(untagged)]
+        Bar,
+        Baz,
+        #[serde(untagged)]
+        Qux,
+    }
+    "#,
+    ) {
+        Err(FromDataEnumError::UntaggedNotAtEnd(ident)) => assert_eq!(ident, "Bar"),
+        x => panic!("{:?}", x),
+    }
+
+    match parse_variants(
+        r#"
+    pub enum Foo {
+        Bar,
+        #[serde(untagged, untagged)]
+        Baz,
+    }
+    "#,
+    ) {
+        Err(FromDataEnumError::FromAttrsFailed(ident, err)) => {
+            assert_eq!(ident, "Baz");
+            match *err {
+                FromAttrsError::DuplicateAttribute(_) => {}
+                x => panic!("{:?}", x),
+            }
+        }
+        x => panic!("{:?}", x),
+    }
+}
+
+#[test]
+fn into_error() {
+    for (input, expected) in [
+        (
+            r#"
+    pub enum Foo {
+        Bar,
+        #[serde(rename = 1)]
+        Baz,
+    }
+    "#,
+            r#"expected serde rename attribute to be a string: `rename = "..."`"#,
+        ),
+        (
+            r#"
+    pub enum Foo {
+        #[serde(untagged)]
+        Bar,
+        Baz,
+    }
+    "#,
+            "all variants with the #[serde(untagged)] attribute must be placed at the end of the enum",
+        ),
+    ] {
+        let err = Error::from(parse_variants(input).unwrap_err());
+        assert_eq!(err.to_string(), expected);
+    }
+}